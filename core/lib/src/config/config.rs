@@ -5,10 +5,12 @@ use serde::{Deserialize, Serialize};
 use yansi::{Paint, Style, Color::Primary};
 
 use crate::log::PaintExt;
-use crate::config::{LogLevel, ShutdownConfig, Ident, CliColors};
+use crate::config::{LogLevel, ShutdownConfig, Ident, CliColors, StartupSummary, ConfigSnapshot};
+use crate::config::Duration;
 use crate::request::{self, Request, FromRequest};
 use crate::http::uncased::Uncased;
 use crate::data::Limits;
+use crate::error::Diagnostic;
 
 #[cfg(feature = "secrets")]
 use crate::config::SecretKey;
@@ -110,8 +112,8 @@ pub struct Config {
     /// [`std::env::temp_dir()`])**
     #[serde(serialize_with = "RelativePathBuf::serialize_relative")]
     pub temp_dir: RelativePathBuf,
-    /// Keep-alive timeout in seconds; disabled when `0`. **(default: `5`)**
-    pub keep_alive: u32,
+    /// Keep-alive timeout; disabled when zero. **(default: `5s`)**
+    pub keep_alive: Duration,
     /// The secret key for signing and encrypting. **(default: `0`)**
     ///
     /// _**Note:** This field _always_ serializes as a 256-bit array of `0`s to
@@ -127,15 +129,31 @@ pub struct Config {
     /// Whether to use colors and emoji when logging. **(default:
     /// [`CliColors::Auto`])**
     pub cli_colors: CliColors,
+    /// Whether to log panics, including their location and, when enabled via
+    /// `RUST_BACKTRACE`, a backtrace, as `error` records with target
+    /// `rocket::panic`. **(default: `true`)**
+    ///
+    /// _**Note:** A panic hook is installed at most once per process and
+    /// consults the value of this parameter in the most recently ignited
+    /// Rocket instance. Panics continue to be reported by any previously
+    /// installed panic hook._
+    pub log_panics: bool,
+    /// How much to log during ignition and liftoff. **(default:
+    /// [`StartupSummary::Full`])**
+    pub startup_summary: StartupSummary,
+    /// Whether to emit a `Server-Timing` header with request phase timings.
+    /// See [`Timings`](crate::request::Timings). **(default: `false`)**
+    pub server_timing: bool,
     /// PRIVATE: This structure may grow (but never change otherwise) in a
     /// non-breaking release. As such, constructing this structure should
     /// _always_ be done using a public constructor or update syntax:
     ///
     /// ```rust
     /// use rocket::Config;
+    /// use rocket::config::Duration;
     ///
     /// let config = Config {
-    ///     keep_alive: 10,
+    ///     keep_alive: Duration::from_secs(10),
     ///     ..Default::default()
     /// };
     /// ```
@@ -197,12 +215,15 @@ impl Config {
             proxy_proto_header: None,
             limits: Limits::default(),
             temp_dir: std::env::temp_dir().into(),
-            keep_alive: 5,
+            keep_alive: Duration::from_secs(5),
             #[cfg(feature = "secrets")]
             secret_key: SecretKey::zero(),
             shutdown: ShutdownConfig::default(),
             log_level: LogLevel::Normal,
             cli_colors: CliColors::Auto,
+            log_panics: true,
+            startup_summary: StartupSummary::Full,
+            server_timing: false,
             __non_exhaustive: (),
         }
     }
@@ -313,6 +334,24 @@ impl Config {
         Self::try_from(provider).unwrap_or_else(bail_with_config_error)
     }
 
+    /// Returns a serializable snapshot of `self` with secrets redacted, using
+    /// `figment`, the figment `self` was extracted from, to determine the
+    /// source of each value. See [`ConfigSnapshot`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Config;
+    ///
+    /// let figment = Config::figment().merge(("workers", 3));
+    /// let config = Config::from(&figment);
+    /// let snapshot = config.snapshot(&figment);
+    /// assert_eq!(snapshot.get("workers").unwrap().to_u128(), Some(3));
+    /// ```
+    pub fn snapshot(&self, figment: &Figment) -> ConfigSnapshot {
+        ConfigSnapshot::new(self, figment)
+    }
+
     #[cfg(feature = "secrets")]
     pub(crate) fn known_secret_key_used(&self) -> bool {
         const KNOWN_SECRET_KEYS: &[&str] = &[
@@ -325,6 +364,61 @@ impl Config {
         })
     }
 
+    /// Returns warnings about deprecated or insecure configuration in `self`,
+    /// extracted from `figment`, for use in a [`PreflightReport`] and for
+    /// logging at launch.
+    ///
+    /// [`PreflightReport`]: crate::error::PreflightReport
+    pub(crate) fn diagnostics(&self, figment: &Figment) -> Vec<Diagnostic> {
+        #[allow(unused_mut)]
+        let mut warnings = Self::deprecation_diagnostics(figment);
+
+        #[cfg(feature = "secrets")]
+        if !self.secret_key.is_provided() {
+            let message = "secrets enabled without configuring a stable `secret_key`";
+            warnings.push(Diagnostic::new("volatile-secret-key", message));
+        } else if self.known_secret_key_used() {
+            let message = "the configured `secret_key` is publicly known and insecure";
+            warnings.push(Diagnostic::new("known-secret-key", message));
+        }
+
+        warnings
+    }
+
+    /// Returns warnings about deprecated keys and profiles in `figment`. Unlike
+    /// [`Config::diagnostics()`], this doesn't require a valid configuration.
+    pub(crate) fn deprecation_diagnostics(figment: &Figment) -> Vec<Diagnostic> {
+        let mut warnings = vec![];
+        for (key, replacement) in Self::DEPRECATED_KEYS {
+            if let Some(md) = figment.find_metadata(key) {
+                let mut message = format!("config key `{key}`");
+                if let Some(ref source) = md.source {
+                    message.push_str(&format!(" in {} {}", source, md.name));
+                }
+
+                match replacement {
+                    Some(new_key) => message.push_str(&format!(" was replaced by `{new_key}`")),
+                    None => message.push_str(" has no special meaning"),
+                }
+
+                warnings.push(Diagnostic::new("deprecated-key", message));
+            }
+        }
+
+        for (prefix, replacement) in Self::DEPRECATED_PROFILES {
+            if let Some(profile) = figment.profiles().find(|p| p.starts_with(prefix)) {
+                let message = match replacement {
+                    Some(new) => format!("profile `{profile}` was replaced by `{new}`"),
+                    None => format!("profile `{profile}` has no special meaning"),
+                };
+
+                warnings.push(Diagnostic::new("deprecated-profile", message));
+            }
+        }
+
+        warnings
+    }
+
     #[inline]
     pub(crate) fn trace_print(&self, figment: &Figment) {
         if self.log_level != LogLevel::Debug {
@@ -332,22 +426,50 @@ impl Config {
         }
 
         trace!("-- configuration trace information --");
-        for param in Self::PARAMETERS {
-            if let Some(meta) = figment.find_metadata(param) {
-                let (param, name) = (param.blue(), meta.name.primary());
-                if let Some(ref source) = meta.source {
-                    trace_!("{:?} parameter source: {} ({})", param, name, source);
-                } else {
-                    trace_!("{:?} parameter source: {}", param, name);
-                }
-            }
+        for (param, source) in Self::parameter_sources(figment) {
+            trace_!("{:?} parameter source: {}", param.blue(), source.primary());
         }
     }
 
+    /// Returns the parameters with a value in `figment` in alphabetical order,
+    /// each with the name and source of the provider and the profile that
+    /// supplied the effective value.
+    ///
+    /// Only the effective value's provenance is available: values which were
+    /// overridden are discarded by `figment` as providers are merged.
+    pub(crate) fn parameter_sources(figment: &Figment) -> Vec<(&'static str, String)> {
+        let mut params = Self::PARAMETERS.to_vec();
+        params.sort_unstable();
+        params.into_iter()
+            .filter_map(|param| {
+                let tag = figment.find_value(param).ok()?.tag();
+                let meta = figment.get_metadata(tag)?;
+                let mut source = match meta.source {
+                    Some(ref source) => format!("{} ({})", meta.name, source),
+                    None => meta.name.to_string(),
+                };
+
+                if let Some(profile) = tag.profile() {
+                    source.push_str(&format!(" [{}]", profile));
+                }
+
+                Some((param, source))
+            })
+            .collect()
+    }
+
     pub(crate) fn pretty_print(&self, figment: &Figment) {
         static VAL: Style = Primary.bold();
 
+        if self.startup_summary == StartupSummary::Quiet {
+            return;
+        }
+
         self.trace_print(figment);
+        if !self.startup_summary.is_full(self.log_level) {
+            return self.print_warnings(figment);
+        }
+
         launch_meta!("{}Configured for {}.", "🔧 ".emoji(), self.profile.underline());
         launch_meta_!("workers: {}", self.workers.paint(VAL));
         launch_meta_!("max blocking threads: {}", self.max_blocking.paint(VAL));
@@ -368,50 +490,36 @@ impl Config {
         launch_meta_!("http/2: {}", (cfg!(feature = "http2").paint(VAL)));
 
         match self.keep_alive {
-            0 => launch_meta_!("keep-alive: {}", "disabled".paint(VAL)),
-            ka => launch_meta_!("keep-alive: {}{}", ka.paint(VAL), "s".paint(VAL)),
+            Duration::ZERO => launch_meta_!("keep-alive: {}", "disabled".paint(VAL)),
+            ka => launch_meta_!("keep-alive: {}", ka.paint(VAL)),
         }
 
         launch_meta_!("shutdown: {}", self.shutdown.paint(VAL));
         launch_meta_!("log level: {}", self.log_level.paint(VAL));
         launch_meta_!("cli colors: {}", self.cli_colors.paint(VAL));
+        launch_meta_!("log panics: {}", self.log_panics.paint(VAL));
+        launch_meta_!("startup summary: {}", self.startup_summary.paint(VAL));
+        launch_meta_!("server timing: {}", self.server_timing.paint(VAL));
 
-        // Check for now deprecated config values.
-        for (key, replacement) in Self::DEPRECATED_KEYS {
-            if let Some(md) = figment.find_metadata(key) {
-                warn!("found value for deprecated config key `{}`", key.paint(VAL));
-                if let Some(ref source) = md.source {
-                    launch_meta_!("in {} {}", source.paint(VAL), md.name);
-                }
-
-                if let Some(new_key) = replacement {
-                    launch_meta_!("key has been by replaced by `{}`", new_key.paint(VAL));
-                } else {
-                    launch_meta_!("key has no special meaning");
-                }
-            }
-        }
+        #[cfg(feature = "secrets")]
+        launch_meta_!("secret key: {}", self.secret_key.paint(VAL));
 
-        // Check for now removed config values.
-        for (prefix, replacement) in Self::DEPRECATED_PROFILES {
-            if let Some(profile) = figment.profiles().find(|p| p.starts_with(prefix)) {
-                warn!("found set deprecated profile `{}`", profile.paint(VAL));
+        self.print_warnings(figment);
+    }
 
-                if let Some(new_profile) = replacement {
-                    launch_meta_!("profile was replaced by `{}`", new_profile.paint(VAL));
-                } else {
-                    launch_meta_!("profile `{}` has no special meaning", profile);
+    fn print_warnings(&self, figment: &Figment) {
+        for diagnostic in self.diagnostics(figment) {
+            warn!("{}", diagnostic.message);
+            match diagnostic.code {
+                "volatile-secret-key" => {
+                    warn_!("private/signed cookies will become unreadable after restarting");
+                    launch_meta_!("disable the `secrets` feature or configure a `secret_key`");
+                    launch_meta_!("this becomes a {} in non-debug profiles", "hard error".red());
                 }
-            }
-        }
-
-        #[cfg(feature = "secrets")] {
-            launch_meta_!("secret key: {}", self.secret_key.paint(VAL));
-            if !self.secret_key.is_provided() {
-                warn!("secrets enabled without configuring a stable `secret_key`");
-                warn_!("private/signed cookies will become unreadable after restarting");
-                launch_meta_!("disable the `secrets` feature or configure a `secret_key`");
-                launch_meta_!("this becomes a {} in non-debug profiles", "hard error".red());
+                "known-secret-key" => {
+                    warn_!("try generating a new key with `head -c64 /dev/urandom | base64`");
+                }
+                _ => { }
             }
         }
     }
@@ -477,12 +585,22 @@ impl Config {
     /// The stringy parameter name for setting/extracting [`Config::cli_colors`].
     pub const CLI_COLORS: &'static str = "cli_colors";
 
+    /// The stringy parameter name for setting/extracting [`Config::log_panics`].
+    pub const LOG_PANICS: &'static str = "log_panics";
+
+    /// The stringy parameter name for setting/extracting [`Config::startup_summary`].
+    pub const STARTUP_SUMMARY: &'static str = "startup_summary";
+
+    /// The stringy parameter name for setting/extracting [`Config::server_timing`].
+    pub const SERVER_TIMING: &'static str = "server_timing";
+
     /// An array of all of the stringy parameter names.
     pub const PARAMETERS: &'static [&'static str] = &[
         Self::WORKERS, Self::MAX_BLOCKING, Self::KEEP_ALIVE, Self::IDENT,
         Self::IP_HEADER, Self::PROXY_PROTO_HEADER, Self::LIMITS,
         Self::SECRET_KEY, Self::TEMP_DIR, Self::LOG_LEVEL, Self::SHUTDOWN,
-        Self::CLI_COLORS,
+        Self::CLI_COLORS, Self::LOG_PANICS, Self::STARTUP_SUMMARY,
+        Self::SERVER_TIMING,
     ];
 }
 
@@ -505,7 +623,7 @@ impl Provider for Config {
         #[cfg(feature = "secrets")]
         if !self.secret_key.is_zero() {
             if let Some(map) = map.get_mut(&Profile::Default) {
-                map.insert("secret_key".into(), self.secret_key.key.master().into());
+                map.insert("secret_key".into(), self.secret_key.expose_master_bytes().into());
             }
         }
 
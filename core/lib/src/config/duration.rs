@@ -0,0 +1,198 @@
+use std::fmt;
+use std::ops::Deref;
+
+use serde::{de, Deserialize, Serialize, Serializer};
+
+/// A non-negative span of time in configuration.
+///
+/// A `Duration` deserializes from any of:
+///
+///   * a non-negative integer number of seconds, e.g. `5`
+///   * a non-negative floating point number of seconds, e.g. `2.5`
+///   * a string consisting of a non-negative number, optionally followed by a
+///     unit, e.g. `"90s"`, `"5m"`, `"1.5h"`, `"250ms"`, or `"30"`
+///
+/// Valid units are `ns`, `us`, `ms`, `s`, `m`, `h`, and `d`. A number without
+/// a unit is interpreted as seconds. Units are case-insensitive and may be
+/// separated from the number by whitespace.
+///
+/// A `Duration` serializes, and displays, as a string in its canonical form:
+/// the largest unit that represents the duration exactly, up to hours. For
+/// example, `90` seconds serializes as `"90s"`, `300` as `"5m"`, and `7200` as
+/// `"2h"`. As such, serialized values always deserialize to the same value.
+///
+/// Configuration parameters which represent a span of time, such as
+/// [`Config::keep_alive`](crate::Config::keep_alive), use this type. So can
+/// configuration values read by libraries and applications:
+///
+/// ```rust
+/// use rocket::serde::Deserialize;
+/// use rocket::config::Duration;
+/// use rocket::figment::{Figment, providers::Serialized};
+///
+/// #[derive(Deserialize)]
+/// #[serde(crate = "rocket::serde")]
+/// struct Cache {
+///     ttl: Duration,
+/// }
+///
+/// let figment = Figment::from(Serialized::default("ttl", "10m"));
+/// let cache: Cache = figment.extract().unwrap();
+/// assert_eq!(cache.ttl, Duration::from_secs(600));
+/// assert_eq!(cache.ttl.to_string(), "10m");
+///
+/// let figment = Figment::from(Serialized::default("ttl", 90));
+/// let cache: Cache = figment.extract().unwrap();
+/// assert_eq!(cache.ttl, Duration::from_secs(90));
+/// assert_eq!(cache.ttl.to_string(), "90s");
+///
+/// let figment = Figment::from(Serialized::default("ttl", "-1s"));
+/// assert!(figment.extract::<Cache>().is_err());
+/// ```
+///
+/// A `Duration` dereferences to a [`std::time::Duration`] and converts to and
+/// from one via [`From`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration(std::time::Duration);
+
+impl Duration {
+    /// A duration of zero time.
+    pub const ZERO: Duration = Duration(std::time::Duration::ZERO);
+
+    /// Creates a new `Duration` of `secs` seconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::Duration;
+    ///
+    /// let duration = Duration::from_secs(120);
+    /// assert_eq!(duration.as_secs(), 120);
+    /// assert_eq!(duration.to_string(), "2m");
+    /// ```
+    pub const fn from_secs(secs: u64) -> Duration {
+        Duration(std::time::Duration::from_secs(secs))
+    }
+
+    /// Creates a new `Duration` of `millis` milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::Duration;
+    ///
+    /// let duration = Duration::from_millis(1500);
+    /// assert_eq!(duration.as_millis(), 1500);
+    /// assert_eq!(duration.to_string(), "1500ms");
+    /// ```
+    pub const fn from_millis(millis: u64) -> Duration {
+        Duration(std::time::Duration::from_millis(millis))
+    }
+
+    fn parse(string: &str) -> Option<Duration> {
+        let string = string.trim();
+        let split = string.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(string.len());
+        let (number, unit) = (string[..split].trim(), &string[split..]);
+        let nanos_per_unit: u64 = match unit.to_ascii_lowercase().as_str() {
+            "ns" => 1,
+            "us" => 1_000,
+            "ms" => 1_000_000,
+            "" | "s" => 1_000_000_000,
+            "m" => 60 * 1_000_000_000,
+            "h" => 60 * 60 * 1_000_000_000,
+            "d" => 24 * 60 * 60 * 1_000_000_000,
+            _ => return None,
+        };
+
+        if number.contains('.') {
+            let value: f64 = number.parse().ok()?;
+            let secs = value * (nanos_per_unit as f64 / 1_000_000_000.0);
+            return std::time::Duration::try_from_secs_f64(secs).ok().map(Duration);
+        }
+
+        let value: u64 = number.parse().ok()?;
+        let nanos = u128::from(value) * u128::from(nanos_per_unit);
+        let secs = u64::try_from(nanos / 1_000_000_000).ok()?;
+        let subsec_nanos = (nanos % 1_000_000_000) as u32;
+        Some(Duration(std::time::Duration::new(secs, subsec_nanos)))
+    }
+}
+
+impl Deref for Duration {
+    type Target = std::time::Duration;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<std::time::Duration> for Duration {
+    fn from(duration: std::time::Duration) -> Self {
+        Duration(duration)
+    }
+}
+
+impl From<Duration> for std::time::Duration {
+    fn from(duration: Duration) -> Self {
+        duration.0
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (secs, nanos) = (self.0.as_secs(), self.0.subsec_nanos());
+        match (secs, nanos) {
+            (0, 0) => write!(f, "0s"),
+            (s, 0) if s % 3600 == 0 => write!(f, "{}h", s / 3600),
+            (s, 0) if s % 60 == 0 => write!(f, "{}m", s / 60),
+            (s, 0) => write!(f, "{}s", s),
+            (_, n) if n % 1_000_000 == 0 => write!(f, "{}ms", self.0.as_millis()),
+            (_, n) if n % 1_000 == 0 => write!(f, "{}us", self.0.as_micros()),
+            _ => write!(f, "{}ns", self.0.as_nanos()),
+        }
+    }
+}
+
+impl Serialize for Duration {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D: de::Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Duration;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a non-negative number of seconds or a duration \
+                    string like \"90s\", \"5m\", or \"2h\"")
+            }
+
+            fn visit_str<E: de::Error>(self, val: &str) -> Result<Duration, E> {
+                Duration::parse(val)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(val), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, val: i64) -> Result<Duration, E> {
+                u64::try_from(val)
+                    .map(Duration::from_secs)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(val), &self))
+            }
+
+            fn visit_u64<E: de::Error>(self, val: u64) -> Result<Duration, E> {
+                Ok(Duration::from_secs(val))
+            }
+
+            fn visit_f64<E: de::Error>(self, val: f64) -> Result<Duration, E> {
+                std::time::Duration::try_from_secs_f64(val)
+                    .map(Duration)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Float(val), &self))
+            }
+        }
+
+        de.deserialize_any(Visitor)
+    }
+}
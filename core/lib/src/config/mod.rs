@@ -114,6 +114,9 @@
 mod ident;
 mod config;
 mod cli_colors;
+mod duration;
+mod startup_summary;
+mod snapshot;
 mod http_header;
 #[cfg(test)]
 mod tests;
@@ -121,6 +124,9 @@ mod tests;
 pub use ident::Ident;
 pub use config::Config;
 pub use cli_colors::CliColors;
+pub use duration::Duration;
+pub use startup_summary::StartupSummary;
+pub use snapshot::{ConfigSnapshot, SnapshotValue};
 
 pub use crate::log::LogLevel;
 pub use crate::shutdown::ShutdownConfig;
@@ -1,4 +1,6 @@
 use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
 
 use cookie::Key;
 use serde::{de, ser, Deserialize, Serialize};
@@ -71,19 +73,32 @@ enum Kind {
 /// assert!(matches!(error.kind(), ErrorKind::InsecureSecretKey(profile)));
 /// ```
 ///
+/// # Key Material
+///
+/// Cloning a `SecretKey` is cheap: all clones share the same key material,
+/// which is overwritten with zeroes when the last clone is dropped. The raw
+/// key bytes are only accessible via [`SecretKey::expose_master_bytes()`].
+///
 /// [private cookies]: https://rocket.rs/master/guide/requests/#private-cookies
 /// [configuration guide]: https://rocket.rs/master/guide/configuration/#secret-key
 #[derive(Clone)]
 #[cfg_attr(nightly, doc(cfg(feature = "secrets")))]
 pub struct SecretKey {
-    pub(crate) key: Key,
+    pub(crate) key: Arc<ZeroizingKey>,
     provided: bool,
 }
 
+/// A `Key` whose bytes are overwritten with zeroes on drop.
+pub(crate) struct ZeroizingKey(pub(super) Key);
+
 impl SecretKey {
+    fn new(key: Key, provided: bool) -> SecretKey {
+        SecretKey { key: Arc::new(ZeroizingKey(key)), provided }
+    }
+
     /// Returns a secret key that is all zeroes.
     pub(crate) fn zero() -> SecretKey {
-        SecretKey { key: Key::from(&[0; 64]), provided: false }
+        SecretKey::new(Key::from(&[0; 64]), false)
     }
 
     /// Creates a `SecretKey` from a 512-bit `master` key. For security,
@@ -102,7 +117,7 @@ impl SecretKey {
     /// let key = SecretKey::from(&master);
     /// ```
     pub fn from(master: &[u8]) -> SecretKey {
-        SecretKey { key: Key::from(master), provided: true }
+        SecretKey::new(Key::from(master), true)
     }
 
     /// Derives a `SecretKey` from 256 bits of cryptographically random
@@ -121,7 +136,7 @@ impl SecretKey {
     /// let key = SecretKey::derive_from(&material);
     /// ```
     pub fn derive_from(material: &[u8]) -> SecretKey {
-        SecretKey { key: Key::derive_from(material), provided: true }
+        SecretKey::new(Key::derive_from(material), true)
     }
 
     /// Attempts to generate a `SecretKey` from randomness retrieved from the
@@ -135,7 +150,7 @@ impl SecretKey {
     /// let key = SecretKey::generate();
     /// ```
     pub fn generate() -> Option<SecretKey> {
-        Some(SecretKey { key: Key::try_generate()?, provided: false })
+        Some(SecretKey::new(Key::try_generate()?, false))
     }
 
     /// Returns `true` if `self` is the `0`-key.
@@ -150,7 +165,7 @@ impl SecretKey {
     /// assert!(key.is_zero());
     /// ```
     pub fn is_zero(&self) -> bool {
-        self == &Self::zero()
+        self.key.0 == Key::from(&[0; 64])
     }
 
     /// Returns `true` if `self` was not automatically generated and is not zero.
@@ -172,6 +187,26 @@ impl SecretKey {
         self.provided && !self.is_zero()
     }
 
+    /// Returns the raw 512-bit master key bytes.
+    ///
+    /// **Danger:** The returned bytes _are_ the secret key. Anyone with access
+    /// to them can forge and decrypt private cookies. They should never be
+    /// logged, serialized, or otherwise persisted outside of secure storage.
+    /// This is the only means by which the raw key material can be accessed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::SecretKey;
+    ///
+    /// # let master = vec![7u8; 64];
+    /// let key = SecretKey::from(&master);
+    /// assert_eq!(key.expose_master_bytes(), &master[..]);
+    /// ```
+    pub fn expose_master_bytes(&self) -> &[u8] {
+        self.key.master()
+    }
+
     /// Serialize as `zero` to avoid key leakage.
     pub(crate) fn serialize_zero<S>(&self, ser: S) -> Result<S::Ok, S::Error>
         where S: ser::Serializer
@@ -183,7 +218,32 @@ impl SecretKey {
 impl PartialEq for SecretKey {
     fn eq(&self, other: &Self) -> bool {
         // `Key::partial_eq()` is a constant-time op.
-        self.key == other.key
+        self.key.0 == other.key.0
+    }
+}
+
+impl Deref for ZeroizingKey {
+    type Target = Key;
+
+    fn deref(&self) -> &Key {
+        &self.0
+    }
+}
+
+impl ZeroizingKey {
+    /// Overwrites the key material in `self` with zeroes.
+    pub(super) fn wipe(&mut self) {
+        // SAFETY: `self.0` is valid for writes and properly aligned. The old
+        // value is overwritten in place without being dropped, which is fine
+        // as `Key` is plain bytes. The write is volatile so it isn't elided.
+        unsafe { std::ptr::write_volatile(&mut self.0, Key::from(&[0; 64])) };
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+impl Drop for ZeroizingKey {
+    fn drop(&mut self) {
+        self.wipe();
     }
 }
 
@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+
+use figment::{Figment, Profile, Provider};
+use figment::providers::Serialized;
+use figment::value::Value;
+use serde::Serialize;
+
+use crate::Config;
+
+/// A serializable, redacted snapshot of an effective configuration.
+///
+/// A `ConfigSnapshot` records the value of every [`Config`] parameter along
+/// with the name and source of the provider that supplied it, if known. It is
+/// created with [`Config::snapshot()`]. Additional parameters, such as those
+/// read by fairings from their own namespaces, can be added with
+/// [`ConfigSnapshot::include()`].
+///
+/// # Redaction
+///
+/// Values which may contain secrets are replaced with the string
+/// `"[redacted]"`. A value is considered secret if the last segment of its
+/// dotted path contains `key` or `secret`, ignoring case, or if its path
+/// starts with a path passed to [`ConfigSnapshot::redact()`]. Redaction
+/// applies recursively to dictionary values and to the elements of arrays,
+/// which share the path of the array. The core `secret_key` is always
+/// redacted.
+///
+/// # Example
+///
+/// A snapshot can be returned from an (appropriately authenticated!) route to
+/// inspect the running configuration:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # #[cfg(feature = "json")] mod _main {
+/// use rocket::request::{self, Request, FromRequest};
+/// use rocket::serde::json::Json;
+/// use rocket::config::ConfigSnapshot;
+///
+/// struct AdminSnapshot(ConfigSnapshot);
+///
+/// #[rocket::async_trait]
+/// impl<'r> FromRequest<'r> for AdminSnapshot {
+///     type Error = ();
+///
+///     async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, ()> {
+///         // Authenticate the request here, then:
+///         let rocket = req.rocket();
+///         let snapshot = rocket.config().snapshot(rocket.figment())
+///             .include(rocket.figment(), "app")
+///             .redact("app.password");
+///
+///         request::Outcome::Success(AdminSnapshot(snapshot))
+///     }
+/// }
+///
+/// #[get("/config")]
+/// fn config(snapshot: AdminSnapshot) -> Json<ConfigSnapshot> {
+///     Json(snapshot.0)
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigSnapshot {
+    profile: String,
+    values: BTreeMap<String, SnapshotValue>,
+    #[serde(skip)]
+    redactions: Vec<String>,
+}
+
+/// A single, possibly redacted, value in a [`ConfigSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SnapshotValue {
+    /// The effective value.
+    pub value: Value,
+    /// The name and source of the provider that supplied the value, if known.
+    pub source: Option<String>,
+}
+
+impl ConfigSnapshot {
+    const REDACTED: &'static str = "[redacted]";
+
+    pub(crate) fn new(config: &Config, figment: &Figment) -> ConfigSnapshot {
+        let mut snapshot = ConfigSnapshot {
+            profile: config.profile.as_str().to_string(),
+            values: BTreeMap::new(),
+            redactions: vec![Config::SECRET_KEY.into()],
+        };
+
+        // Serialize via `Serialized` as `Config`'s provider reveals the key.
+        let data = Serialized::defaults(config).data().unwrap_or_default();
+        if let Some(dict) = data.get(&Profile::Default) {
+            for (key, value) in dict {
+                snapshot.insert(figment, key, value.clone());
+            }
+        }
+
+        snapshot
+    }
+
+    /// Adds the effective value at the dotted `path` in `figment` to the
+    /// snapshot, if there is one, redacting as necessary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Config;
+    ///
+    /// let figment = Config::figment()
+    ///     .merge(("app", rocket::figment::util::map!["api_key" => "hunter2"]));
+    ///
+    /// let config = Config::from(&figment);
+    /// let snapshot = config.snapshot(&figment).include(&figment, "app");
+    /// assert_eq!(snapshot.get("app.api_key").unwrap().as_str(), Some("[redacted]"));
+    /// ```
+    pub fn include(mut self, figment: &Figment, path: &str) -> Self {
+        if let Ok(value) = figment.find_value(path) {
+            self.insert(figment, path, value);
+        }
+
+        self
+    }
+
+    /// Redacts the value at the dotted `path`, and any values nested inside
+    /// of it, both now and in any subsequently included values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Config;
+    ///
+    /// let figment = Config::figment().merge(("app.user", "bob"));
+    /// let config = Config::from(&figment);
+    /// let snapshot = config.snapshot(&figment)
+    ///     .redact("app.user")
+    ///     .include(&figment, "app");
+    ///
+    /// assert_eq!(snapshot.get("app.user").unwrap().as_str(), Some("[redacted]"));
+    /// ```
+    pub fn redact(mut self, path: &str) -> Self {
+        self.redactions.push(path.into());
+        let values = std::mem::take(&mut self.values);
+        for (key, entry) in values {
+            let value = self.redacted(&key, entry.value);
+            self.values.insert(key, SnapshotValue { value, ..entry });
+        }
+
+        self
+    }
+
+    /// The name of the profile the configuration was extracted from.
+    pub fn profile(&self) -> &str {
+        &self.profile
+    }
+
+    /// Returns the top-level entries in the snapshot in key order.
+    pub fn values(&self) -> impl Iterator<Item = (&str, &SnapshotValue)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Returns the value at the dotted `path`, if there is one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Config;
+    ///
+    /// let figment = Config::figment().merge(("keep_alive", 10));
+    /// let config = Config::from(&figment);
+    /// let snapshot = config.snapshot(&figment);
+    /// assert_eq!(snapshot.get("keep_alive").unwrap().as_str(), Some("10s"));
+    /// ```
+    pub fn get(&self, path: &str) -> Option<&Value> {
+        let (top, rest) = match path.split_once('.') {
+            Some((top, rest)) => (top, Some(rest)),
+            None => (path, None),
+        };
+
+        if let Some(entry) = self.values.get(path) {
+            return Some(&entry.value);
+        }
+
+        let value = &self.values.get(top)?.value;
+        match rest {
+            Some(rest) => value.find_ref(rest),
+            None => Some(value),
+        }
+    }
+
+    fn insert(&mut self, figment: &Figment, path: &str, value: Value) {
+        let source = figment.find_metadata(path).map(|md| match md.source {
+            Some(ref source) => format!("{} ({})", md.name, source),
+            None => md.name.to_string(),
+        });
+
+        let value = self.redacted(path, value);
+        self.values.insert(path.into(), SnapshotValue { value, source });
+    }
+
+    fn is_sensitive(&self, path: &str) -> bool {
+        let last = path.rsplit('.').next().unwrap_or(path).to_ascii_lowercase();
+        let redacted = |r: &String| match path.strip_prefix(r.as_str()) {
+            Some(rest) => rest.is_empty() || rest.starts_with('.'),
+            None => false,
+        };
+
+        last.contains("key") || last.contains("secret") || self.redactions.iter().any(redacted)
+    }
+
+    fn redacted(&self, path: &str, value: Value) -> Value {
+        if self.is_sensitive(path) {
+            return Value::from(Self::REDACTED);
+        }
+
+        match value {
+            Value::Dict(tag, dict) => {
+                let dict = dict.into_iter()
+                    .map(|(k, v)| {
+                        let v = self.redacted(&format!("{}.{}", path, k), v);
+                        (k, v)
+                    })
+                    .collect();
+
+                Value::Dict(tag, dict)
+            }
+            Value::Array(tag, array) => {
+                let array = array.into_iter().map(|v| self.redacted(path, v)).collect();
+                Value::Array(tag, array)
+            }
+            value => value,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// How much to log when a Rocket application ignites and lifts off.
+///
+/// Valid configuration values are:
+///
+///   * `"full"` - [`StartupSummary::Full`] _(default)_
+///   * `"compact"` - [`StartupSummary::Compact`]
+///   * `"quiet"` - [`StartupSummary::Quiet`]
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum StartupSummary {
+    /// Log the configuration, routes, catchers, fairings, and a launch banner.
+    #[default]
+    Full,
+
+    /// Log a single line summarizing the launch, such as:
+    ///
+    /// ```text
+    /// rocket launched addr=127.0.0.1:8000 routes=14 catchers=3 fairings=5 profile=release
+    /// ```
+    ///
+    /// Warnings are still logged. The full listing is logged as well when
+    /// [`LogLevel::Debug`](crate::config::LogLevel::Debug) is configured.
+    Compact,
+
+    /// Log nothing during ignition and liftoff other than errors.
+    Quiet,
+}
+
+impl StartupSummary {
+    /// Returns `true` if the full startup listing should be logged given the
+    /// configured `log_level`.
+    pub(crate) fn is_full(self, log_level: crate::config::LogLevel) -> bool {
+        self == StartupSummary::Full
+            || (self == StartupSummary::Compact && log_level == crate::config::LogLevel::Debug)
+    }
+}
+
+impl fmt::Display for StartupSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StartupSummary::Full => write!(f, "full"),
+            StartupSummary::Compact => write!(f, "compact"),
+            StartupSummary::Quiet => write!(f, "quiet"),
+        }
+    }
+}
@@ -3,7 +3,7 @@ use pretty_assertions::assert_eq;
 
 use crate::log::LogLevel;
 use crate::data::{Limits, ToByteUnit};
-use crate::config::{Config, CliColors};
+use crate::config::{Config, CliColors, StartupSummary, Duration};
 
 #[test]
 fn test_figment_is_default() {
@@ -64,7 +64,7 @@ fn test_toml_file() {
         assert_eq!(config, Config {
             workers: 20,
             ident: ident!("Something Cool"),
-            keep_alive: 10,
+            keep_alive: Duration::from_secs(10),
             log_level: LogLevel::Off,
             cli_colors: CliColors::Never,
             ..Config::default()
@@ -83,7 +83,7 @@ fn test_toml_file() {
         assert_eq!(config, Config {
             workers: 20,
             ident: ident!("Something Else Cool"),
-            keep_alive: 10,
+            keep_alive: Duration::from_secs(10),
             log_level: LogLevel::Off,
             cli_colors: CliColors::Never,
             ..Config::default()
@@ -101,7 +101,7 @@ fn test_toml_file() {
         let config = Config::from(Config::figment());
         assert_eq!(config, Config {
             workers: 20,
-            keep_alive: 10,
+            keep_alive: Duration::from_secs(10),
             log_level: LogLevel::Off,
             cli_colors: CliColors::Never,
             ..Config::default()
@@ -111,6 +111,201 @@ fn test_toml_file() {
     });
 }
 
+#[test]
+fn test_log_panics() {
+    figment::Jail::expect_with(|jail| {
+        let config = Config::from(Config::figment());
+        assert!(config.log_panics);
+
+        jail.create_file("Rocket.toml", r#"
+                [default]
+                log_panics = false
+            "#)?;
+
+        let config = Config::from(Config::figment());
+        assert!(!config.log_panics);
+
+        jail.set_env("ROCKET_LOG_PANICS", true);
+        let config = Config::from(Config::figment());
+        assert!(config.log_panics);
+
+        jail.set_env("ROCKET_LOG_PANICS", "maybe");
+        assert!(Config::try_from(Config::figment()).is_err());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn test_startup_summary() {
+    figment::Jail::expect_with(|jail| {
+        let config = Config::from(Config::figment());
+        assert_eq!(config.startup_summary, StartupSummary::Full);
+
+        jail.create_file("Rocket.toml", r#"
+                [default]
+                startup_summary = "compact"
+
+                [release]
+                startup_summary = "quiet"
+            "#)?;
+
+        let config = Config::from(Config::figment().select("debug"));
+        assert_eq!(config.startup_summary, StartupSummary::Compact);
+
+        let config = Config::from(Config::figment().select("release"));
+        assert_eq!(config.startup_summary, StartupSummary::Quiet);
+
+        jail.set_env("ROCKET_STARTUP_SUMMARY", "full");
+        let config = Config::from(Config::figment().select("release"));
+        assert_eq!(config.startup_summary, StartupSummary::Full);
+
+        jail.set_env("ROCKET_STARTUP_SUMMARY", "terse");
+        assert!(Config::try_from(Config::figment()).is_err());
+
+        Ok(())
+    });
+
+    assert!(StartupSummary::Full.is_full(LogLevel::Critical));
+    assert!(!StartupSummary::Compact.is_full(LogLevel::Normal));
+    assert!(StartupSummary::Compact.is_full(LogLevel::Debug));
+    assert!(!StartupSummary::Quiet.is_full(LogLevel::Debug));
+}
+
+#[test]
+fn test_durations() {
+    figment::Jail::expect_with(|jail| {
+        let config = Config::from(Config::figment());
+        assert_eq!(config.keep_alive, Duration::from_secs(5));
+        assert_eq!(config.shutdown.grace, Duration::from_secs(2));
+        assert_eq!(config.shutdown.mercy, Duration::from_secs(3));
+
+        jail.create_file("Rocket.toml", r#"
+                [default]
+                keep_alive = 0
+
+                [default.shutdown]
+                grace = 10
+                mercy = 2.5
+            "#)?;
+
+        let config = Config::from(Config::figment());
+        assert_eq!(config.keep_alive, Duration::ZERO);
+        assert_eq!(config.shutdown.grace, Duration::from_secs(10));
+        assert_eq!(config.shutdown.mercy, Duration::from_millis(2500));
+
+        jail.create_file("Rocket.toml", r#"
+                [default]
+                keep_alive = "90s"
+
+                [default.shutdown]
+                grace = "5m"
+                mercy = "250ms"
+
+                [debug]
+                keep_alive = "1h"
+            "#)?;
+
+        let config = Config::from(Config::figment());
+        assert_eq!(config.keep_alive, Duration::from_secs(3600));
+        assert_eq!(config.shutdown.grace, Duration::from_secs(300));
+        assert_eq!(config.shutdown.mercy, Duration::from_millis(250));
+
+        let config = Config::from(Config::figment().select("release"));
+        assert_eq!(config.keep_alive, Duration::from_secs(90));
+
+        jail.set_env("ROCKET_KEEP_ALIVE", "2 m");
+        let config = Config::from(Config::figment());
+        assert_eq!(config.keep_alive, Duration::from_secs(120));
+
+        jail.set_env("ROCKET_KEEP_ALIVE", 7);
+        let config = Config::from(Config::figment());
+        assert_eq!(config.keep_alive, Duration::from_secs(7));
+
+        for invalid in ["-1", "-1s", "-0.5", "5 parsecs", "s", "1.5.2m", "inf"] {
+            jail.set_env("ROCKET_KEEP_ALIVE", invalid);
+            assert!(Config::try_from(Config::figment()).is_err(), "{}", invalid);
+        }
+
+        Ok(())
+    });
+
+    let units = [
+        ("30", Duration::from_secs(30)),
+        ("30s", Duration::from_secs(30)),
+        ("2m", Duration::from_secs(120)),
+        ("2h", Duration::from_secs(7200)),
+        ("1d", Duration::from_secs(86400)),
+        ("1.5s", Duration::from_millis(1500)),
+        ("15ms", Duration::from_millis(15)),
+        ("15us", std::time::Duration::from_micros(15).into()),
+        ("15ns", std::time::Duration::from_nanos(15).into()),
+    ];
+
+    for (string, expected) in units {
+        let figment = Figment::from(("value", string));
+        assert_eq!(figment.extract_inner::<Duration>("value").unwrap(), expected);
+    }
+
+    // Serialization is to a canonical string that round-trips.
+    for (duration, string) in [
+        (Duration::ZERO, "0s"),
+        (Duration::from_secs(45), "45s"),
+        (Duration::from_secs(300), "5m"),
+        (Duration::from_secs(86400), "24h"),
+        (Duration::from_millis(1500), "1500ms"),
+        (std::time::Duration::from_nanos(1_000_000_001).into(), "1000000001ns"),
+    ] {
+        let figment = Figment::from(("value", duration));
+        assert_eq!(figment.extract_inner::<String>("value").unwrap(), string);
+        assert_eq!(figment.extract_inner::<Duration>("value").unwrap(), duration);
+    }
+
+    let figment = Figment::from(Config::default());
+    let keep_alive: Duration = figment.extract_inner(Config::KEEP_ALIVE).unwrap();
+    assert_eq!(keep_alive, Config::default().keep_alive);
+}
+
+#[test]
+fn test_parameter_sources() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file("Rocket.toml", r#"
+                [default]
+                keep_alive = 10
+                workers = 2
+
+                [debug]
+                workers = 3
+            "#)?;
+
+        jail.set_env("ROCKET_WORKERS", 4);
+        jail.set_env("ROCKET_LOG_LEVEL", "off");
+        let figment = Config::figment().select("debug").merge(("workers", 5));
+
+        let sources = Config::parameter_sources(&figment);
+        let params: Vec<_> = sources.iter().map(|(param, _)| *param).collect();
+        let mut sorted = params.clone();
+        sorted.sort();
+        assert_eq!(params, sorted);
+        assert_eq!(sources, Config::parameter_sources(&figment));
+
+        let source = |param| sources.iter().find(|(p, _)| *p == param).unwrap().1.clone();
+        assert!(source(Config::KEEP_ALIVE).contains("Rocket.toml"));
+        assert!(source(Config::KEEP_ALIVE).ends_with("[default]"));
+        assert!(source(Config::LOG_LEVEL).contains("ROCKET_"));
+        assert!(source(Config::LOG_LEVEL).ends_with("[global]"));
+        assert!(!source(Config::WORKERS).contains("Rocket.toml"));
+        assert!(!source(Config::WORKERS).contains("ROCKET_"));
+
+        let figment = Config::figment().select("debug");
+        let sources = Config::parameter_sources(&figment);
+        let source = |param| sources.iter().find(|(p, _)| *p == param).unwrap().1.clone();
+        assert!(source(Config::WORKERS).contains("ROCKET_"));
+
+        Ok(())
+    });
+}
+
 #[test]
 fn test_cli_colors() {
     figment::Jail::expect_with(|jail| {
@@ -253,7 +448,7 @@ fn test_env_vars_merge() {
         jail.set_env("ROCKET_KEEP_ALIVE", 9999);
         let config = Config::from(Config::figment());
         assert_eq!(config, Config {
-            keep_alive: 9999,
+            keep_alive: Duration::from_secs(9999),
             ..Config::default()
         });
 
@@ -263,24 +458,36 @@ fn test_env_vars_merge() {
         let prev_figment = Config::figment().join(&first_figment);
         let config = Config::from(&prev_figment);
         assert_eq!(config, Config {
-            keep_alive: 9999,
-            shutdown: ShutdownConfig { grace: 7, mercy: 10, ..Default::default() },
+            keep_alive: Duration::from_secs(9999),
+            shutdown: ShutdownConfig {
+                grace: Duration::from_secs(7),
+                mercy: Duration::from_secs(10),
+                ..Default::default()
+            },
             ..Config::default()
         });
 
         jail.set_env("ROCKET_SHUTDOWN", r#"{mercy=20}"#);
         let config = Config::from(Config::figment().join(&prev_figment));
         assert_eq!(config, Config {
-            keep_alive: 9999,
-            shutdown: ShutdownConfig { grace: 7, mercy: 20, ..Default::default() },
+            keep_alive: Duration::from_secs(9999),
+            shutdown: ShutdownConfig {
+                grace: Duration::from_secs(7),
+                mercy: Duration::from_secs(20),
+                ..Default::default()
+            },
             ..Config::default()
         });
 
         jail.set_env("ROCKET_LIMITS", r#"{stream=100kiB}"#);
         let config = Config::from(Config::figment().join(&prev_figment));
         assert_eq!(config, Config {
-            keep_alive: 9999,
-            shutdown: ShutdownConfig { grace: 7, mercy: 20, ..Default::default() },
+            keep_alive: Duration::from_secs(9999),
+            shutdown: ShutdownConfig {
+                grace: Duration::from_secs(7),
+                mercy: Duration::from_secs(20),
+                ..Default::default()
+            },
             limits: Limits::default().limit("stream", 100.kibibytes()),
             ..Config::default()
         });
@@ -288,8 +495,12 @@ fn test_env_vars_merge() {
         jail.set_env("ROCKET_IDENT", false);
         let config = Config::from(Config::figment().join(&prev_figment));
         assert_eq!(config, Config {
-            keep_alive: 9999,
-            shutdown: ShutdownConfig { grace: 7, mercy: 20, ..Default::default() },
+            keep_alive: Duration::from_secs(9999),
+            shutdown: ShutdownConfig {
+                grace: Duration::from_secs(7),
+                mercy: Duration::from_secs(20),
+                ..Default::default()
+            },
             limits: Limits::default().limit("stream", 100.kibibytes()),
             ident: Ident::none(),
             ..Config::default()
@@ -392,3 +603,114 @@ fn test_no_err_on_release_and_custom_secret_key() {
         Ok(())
     });
 }
+
+#[test]
+#[cfg(feature = "secrets")]
+fn test_secret_key_clones_share_material() {
+    use crate::config::SecretKey;
+
+    let master: Vec<u8> = (0..64).collect();
+    let key = SecretKey::from(&master);
+    let clones: Vec<_> = (0..4).map(|_| key.clone()).collect();
+    assert!(clones.iter().all(|k| k == &key));
+    assert!(clones.iter().all(|k| std::sync::Arc::ptr_eq(&k.key, &key.key)));
+
+    drop(clones);
+    assert_eq!(key.expose_master_bytes(), &master[..]);
+    assert!(key.is_provided());
+}
+
+#[test]
+#[cfg(feature = "secrets")]
+fn test_secret_key_wipe() {
+    use crate::config::secret_key::ZeroizingKey;
+
+    let master: Vec<u8> = (1..=64).collect();
+    let mut key = ZeroizingKey(cookie::Key::from(&master));
+    assert_eq!(key.master(), &master[..]);
+
+    key.wipe();
+    assert!(key.master().iter().all(|b| *b == 0));
+}
+
+#[test]
+fn test_config_snapshot() {
+    use figment::value::Value;
+
+    figment::Jail::expect_with(|jail| {
+        jail.create_file("Rocket.toml", r#"
+                [default]
+                workers = 7
+                secret_key = "hPRYyVRiMyxpw5sBB1XeCMN1kFsDCqKvBi2QJxBVHQk="
+
+                [default.csrf]
+                cookie = "__csrf"
+                hmac_key = "abc"
+
+                [default.csrf.rotate]
+                period = 24
+            "#)?;
+
+        let figment = Config::figment();
+        let config = Config::from(&figment);
+        let snapshot = config.snapshot(&figment);
+        assert_eq!(snapshot.profile(), config.profile.as_str());
+        assert_eq!(snapshot.get("workers").unwrap().to_u128(), Some(7));
+        #[cfg(feature = "secrets")]
+        assert_eq!(snapshot.get("secret_key").unwrap().as_str(), Some("[redacted]"));
+        assert!(snapshot.get("csrf").is_none());
+
+        let source = snapshot.values().find(|(k, _)| *k == "workers").unwrap().1;
+        assert!(source.source.as_ref().unwrap().contains("Rocket.toml"));
+
+        let snapshot = snapshot.include(&figment, "csrf").redact("csrf.rotate");
+        assert_eq!(snapshot.get("csrf.cookie").unwrap().as_str(), Some("__csrf"));
+        assert_eq!(snapshot.get("csrf.hmac_key").unwrap().as_str(), Some("[redacted]"));
+        assert_eq!(snapshot.get("csrf.rotate").unwrap().as_str(), Some("[redacted]"));
+
+        let first = Value::serialize(&snapshot).unwrap();
+        let second = Value::serialize(config.snapshot(&figment).include(&figment, "csrf")
+            .redact("csrf.rotate")).unwrap();
+
+        assert_eq!(first, second);
+        assert!(!format!("{:?}", first).contains("hPRYyVRiMyxpw5sBB1XeCMN1kFsDCqKvBi2QJxBVHQk="));
+        Ok(())
+    });
+}
+
+#[test]
+fn test_config_snapshot_redacts_arrays() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file("Rocket.toml", r#"
+                [default.app]
+                tokens = ["t0k3n"]
+
+                [[default.app.upstreams]]
+                url = "https://a.example"
+                api_key = "hunter2"
+
+                [[default.app.upstreams]]
+                url = "https://b.example"
+                api_key = "hunter3"
+            "#)?;
+
+        let figment = Config::figment();
+        let config = Config::from(&figment);
+        let snapshot = config.snapshot(&figment)
+            .include(&figment, "app")
+            .redact("app.tokens");
+
+        let upstreams = snapshot.get("app.upstreams").unwrap().as_array().unwrap();
+        assert_eq!(upstreams.len(), 2);
+        for upstream in upstreams {
+            assert!(upstream.find_ref("url").unwrap().as_str().unwrap().starts_with("https"));
+            assert_eq!(upstream.find_ref("api_key").unwrap().as_str(), Some("[redacted]"));
+        }
+
+        assert_eq!(snapshot.get("app.tokens").unwrap().as_str(), Some("[redacted]"));
+
+        let debug = format!("{:?}", snapshot);
+        assert!(!debug.contains("hunter") && !debug.contains("t0k3n"));
+        Ok(())
+    });
+}
@@ -99,6 +99,79 @@ pub enum ErrorKind {
     Shutdown(Arc<Rocket<Orbit>>),
 }
 
+/// The outcome of [`Rocket::preflight()`]: every error and warning detected
+/// while igniting an instance.
+///
+/// Each entry is a [`Diagnostic`] with a stable, machine-readable code. A
+/// report with no errors or warnings is _clean_.
+///
+/// # Example
+///
+/// ```rust
+/// # rocket::async_test(async {
+/// let report = rocket::build().preflight().await;
+/// for warning in &report.warnings {
+///     println!("{}: {}", warning.code, warning.message);
+/// }
+///
+/// assert!(report.errors.is_empty());
+/// # });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PreflightReport {
+    /// Errors which prevent the instance from launching.
+    pub errors: Vec<Diagnostic>,
+    /// Warnings about deprecated or insecure configuration, or recorded by
+    /// fairings.
+    pub warnings: Vec<Diagnostic>,
+}
+
+/// A single error or warning in a [`PreflightReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// A stable, machine-readable code identifying the kind of problem.
+    ///
+    /// The following codes are emitted by Rocket:
+    ///
+    ///   * errors: `bind`, `io`, `config`, `collisions`, `failed-fairings`,
+    ///     `sentinel-aborts`, `insecure-secret-key`, `liftoff`, `shutdown`
+    ///   * warnings: `deprecated-key`, `deprecated-profile`,
+    ///     `volatile-secret-key`, `known-secret-key`
+    ///
+    /// Other codes may be recorded by fairings via
+    /// [`Rocket::preflight_error()`] and [`Rocket::preflight_warning()`].
+    pub code: &'static str,
+    /// A human-readable description of the problem.
+    pub message: String,
+}
+
+impl PreflightReport {
+    /// Returns `true` if the report contains neither errors nor warnings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::error::PreflightReport;
+    ///
+    /// assert!(PreflightReport::default().is_clean());
+    /// ```
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty() && self.warnings.is_empty()
+    }
+}
+
+impl Diagnostic {
+    pub(crate) fn new<M: Into<String>>(code: &'static str, message: M) -> Self {
+        Diagnostic { code, message: message.into() }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.code, self.message)
+    }
+}
+
 /// An error that occurs when a value was unexpectedly empty.
 #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Empty;
@@ -252,6 +325,67 @@ impl Error {
     }
 }
 
+impl From<Error> for Diagnostic {
+    fn from(error: Error) -> Self {
+        let code = match error.kind() {
+            ErrorKind::Bind(..) => "bind",
+            ErrorKind::Io(_) => "io",
+            ErrorKind::Config(_) => "config",
+            ErrorKind::Collisions(_) => "collisions",
+            ErrorKind::FailedFairings(_) => "failed-fairings",
+            ErrorKind::SentinelAborts(_) => "sentinel-aborts",
+            ErrorKind::InsecureSecretKey(_) => "insecure-secret-key",
+            ErrorKind::Liftoff(..) => "liftoff",
+            ErrorKind::Shutdown(_) => "shutdown",
+        };
+
+        let details: Vec<String> = match error.kind() {
+            ErrorKind::Config(e) => e.clone().into_iter().map(|e| e.to_string()).collect(),
+            ErrorKind::Collisions(collisions) => {
+                // Avoid `Display`, which may emit terminal color codes.
+                fn route(r: &crate::Route) -> String {
+                    let name = r.name.as_deref().unwrap_or("unnamed");
+                    format!("route `{name}` ({} {})", r.method, r.uri)
+                }
+
+                fn catcher(c: &crate::Catcher) -> String {
+                    let name = c.name.as_deref().unwrap_or("unnamed");
+                    match c.code {
+                        Some(code) => format!("catcher `{name}` ({code} {})", c.base()),
+                        None => format!("catcher `{name}` (default {})", c.base()),
+                    }
+                }
+
+                let routes = collisions.routes.iter()
+                    .map(|(a, b)| format!("{} collides with {}", route(a), route(b)));
+
+                let catchers = collisions.catchers.iter()
+                    .map(|(a, b)| format!("{} collides with {}", catcher(a), catcher(b)));
+
+                routes.chain(catchers).collect()
+            }
+            ErrorKind::FailedFairings(failures) => failures.iter()
+                .map(|fairing| format!("fairing `{}` failed", fairing.name))
+                .collect(),
+            ErrorKind::SentinelAborts(sentries) => sentries.iter()
+                .map(|s| format!("type `{}` at {}:{}:{}",
+                    s.type_name, s.location.0, s.location.1, s.location.2))
+                .collect(),
+            ErrorKind::InsecureSecretKey(profile) => {
+                vec![format!("profile `{profile}` requires a configured `secret_key`")]
+            }
+            _ => vec![],
+        };
+
+        let message = match details.is_empty() {
+            true => error.kind().to_string(),
+            false => format!("{}: {}", error.kind(), details.join("; ")),
+        };
+
+        Diagnostic::new(code, message)
+    }
+}
+
 impl std::error::Error for Error {  }
 
 impl fmt::Display for ErrorKind {
@@ -170,6 +170,10 @@ impl Fairings {
         }
     }
 
+    pub fn active_count(&self) -> usize {
+        self.active().collect::<HashSet<_>>().len()
+    }
+
     pub fn pretty_print(&self) {
         let active_fairings = self.active().collect::<HashSet<_>>();
         if !active_fairings.is_empty() {
@@ -434,6 +434,66 @@ impl<'a> CookieJar<'a> {
         self.ops.lock().push(Op::Remove(cookie));
     }
 
+    /// Removes every cookie whose name starts with `prefix`, including both
+    /// _original_ cookies and pending additions, generating a "removal" cookie
+    /// for each as [`CookieJar::remove()`] does.
+    ///
+    /// For a pending cookie, the removal cookie uses the `path` and `domain` of
+    /// the latest pending addition. Since browsers do not send these
+    /// attributes with requests, the removal cookie for an _original_ cookie
+    /// uses the defaults of [`CookieJar::remove()`]. Cookies originally set
+    /// with a custom `path` or `domain` must be removed individually with
+    /// matching attributes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::CookieJar;
+    ///
+    /// #[get("/logout")]
+    /// fn logout(jar: &CookieJar<'_>) {
+    ///     // Removes `session_id`, `session_user`, and so on.
+    ///     jar.remove_matching("session_");
+    /// }
+    /// ```
+    pub fn remove_matching(&self, prefix: &str) {
+        let mut ops = self.ops.lock();
+        let mut seen: Vec<String> = vec![];
+        let mut removals: Vec<Cookie<'static>> = vec![];
+        for op in ops.iter().rev() {
+            let name = op.cookie().name();
+            if !name.starts_with(prefix) || seen.iter().any(|n| n == name) {
+                continue;
+            }
+
+            seen.push(name.to_string());
+            if let Op::Add(cookie, _) = op {
+                let mut removal = Cookie::new(name.to_string(), "");
+                if let Some(path) = cookie.path() {
+                    removal.set_path(path.to_string());
+                }
+
+                if let Some(domain) = cookie.domain() {
+                    removal.set_domain(domain.to_string());
+                }
+
+                removals.push(removal);
+            }
+        }
+
+        for cookie in self.jar.iter() {
+            if cookie.name().starts_with(prefix) && !seen.iter().any(|n| n == cookie.name()) {
+                removals.push(Cookie::new(cookie.name().to_string(), ""));
+            }
+        }
+
+        for mut cookie in removals {
+            Self::set_removal_defaults(&mut cookie);
+            ops.push(Op::Remove(cookie));
+        }
+    }
+
     /// Returns an iterator over all of the _original_ cookies present in this
     /// collection.
     ///
@@ -158,6 +158,7 @@ mod util;
 mod server;
 mod lifecycle;
 mod state;
+mod liftoff;
 mod router;
 mod phase;
 mod erased;
@@ -178,6 +179,7 @@ mod erased;
 #[doc(inline)] pub use crate::rkt::Rocket;
 #[doc(inline)] pub use crate::shutdown::Shutdown;
 #[doc(inline)] pub use crate::state::State;
+#[doc(inline)] pub use crate::liftoff::LiftoffInfo;
 
 /// Retrofits support for `async fn` in trait impls and declarations.
 ///
@@ -1,11 +1,14 @@
 use yansi::Paint;
 use futures::future::{FutureExt, Future};
 
+use std::time::{Duration, Instant};
+
 use crate::{route, Rocket, Orbit, Request, Response, Data};
 use crate::data::IoHandler;
-use crate::http::{Method, Status, Header};
+use crate::http::{Method, Status, StatusClass, Header};
 use crate::outcome::Outcome;
 use crate::form::Form;
+use crate::request::Timings;
 
 // A token returned to force the execution of one method before another.
 pub(crate) struct RequestToken;
@@ -57,6 +60,11 @@ impl Rocket<Orbit> {
         req: &mut Request<'_>,
         data: &mut Data<'_>
     ) -> RequestToken {
+        // Start the `Server-Timing` clock before any other processing.
+        if self.config.server_timing {
+            Timings::of(req);
+        }
+
         // Check if this is a form and if the form contains the special _method
         // field which we use to reinterpret the request's method.
         if req.method() == Method::Post && req.content_type().map_or(false, |v| v.is_form()) {
@@ -97,6 +105,7 @@ impl Rocket<Orbit> {
         // io_stream: impl Future<Output = io::Result<IoStream>> + Send,
     ) -> Response<'r> {
         info!("{}:", request);
+        let routing_start = Instant::now();
 
         // Remember if the request is `HEAD` for later body stripping.
         let was_head_request = request.method() == Method::Head;
@@ -119,6 +128,8 @@ impl Rocket<Orbit> {
             Outcome::Error(status) => self.dispatch_error(status, request).await,
         };
 
+        let handler = routing_start.elapsed();
+
         // Set the cookies. Note that error responses will only include cookies
         // set by the error handler. See `handle_error` for more.
         let delta_jar = request.cookies().take_delta_jar();
@@ -137,6 +148,9 @@ impl Rocket<Orbit> {
         // Run the response fairings.
         self.fairings.handle_response(request, &mut response).await;
 
+        // Emit the `Server-Timing` header, if enabled, after response fairings.
+        self.set_server_timing(request, &mut response, Some(handler));
+
         // Strip the body if this is a `HEAD` request.
         if was_head_request {
             response.strip_body();
@@ -154,6 +168,31 @@ impl Rocket<Orbit> {
         response
     }
 
+    // Sets the `Server-Timing` header on `response` if it is enabled, the
+    // response isn't informational, and the request isn't an upgrade request.
+    // `handler` is the time spent routing and running handlers, if any.
+    pub(crate) fn set_server_timing(
+        &self,
+        req: &Request<'_>,
+        response: &mut Response<'_>,
+        handler: Option<Duration>,
+    ) {
+        if !self.config.server_timing
+            || response.status().class() == StatusClass::Informational
+            || req.headers().contains("Upgrade")
+        {
+            return;
+        }
+
+        let timings = Timings::of(req);
+        if let Some(handler) = handler {
+            timings.add("handler", handler);
+        }
+
+        timings.add("total", timings.elapsed());
+        response.set_raw_header("Server-Timing", timings.to_string());
+    }
+
     pub(crate) fn extract_io_handler<'r>(
         request: &Request<'_>,
         response: &mut Response<'r>,
@@ -0,0 +1,71 @@
+use std::time::SystemTime;
+
+use figment::Profile;
+
+use crate::listener::Endpoint;
+
+/// Facts about a launched instance of Rocket.
+///
+/// A `LiftoffInfo` is created just before liftoff fairings run and is available
+/// via [`Rocket::liftoff_info()`](crate::Rocket::liftoff_info()) for the
+/// lifetime of the orbiting instance. It can be used to, for example, register
+/// an instance with a service discovery system without inspecting log output.
+/// Rocket's own launch message is generated from the same `LiftoffInfo`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # #[macro_use] extern crate rocket;
+/// use rocket::fairing::AdHoc;
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build()
+///         .attach(AdHoc::on_liftoff("Register", |rocket| Box::pin(async move {
+///             let info = rocket.liftoff_info();
+///             for endpoint in info.endpoints() {
+///                 if let Some(addr) = endpoint.socket_addr() {
+///                     println!("registering {} (tls: {})", addr, endpoint.is_tls());
+///                 }
+///             }
+///         })))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiftoffInfo {
+    endpoints: Vec<Endpoint>,
+    profile: Profile,
+    workers: usize,
+    start_time: SystemTime,
+}
+
+impl LiftoffInfo {
+    pub(crate) fn new(endpoints: Vec<Endpoint>, profile: Profile, workers: usize) -> Self {
+        LiftoffInfo { endpoints, profile, workers, start_time: SystemTime::now() }
+    }
+
+    /// Returns all of the endpoints the instance is bound to, in order. The
+    /// first endpoint is the primary endpoint.
+    ///
+    /// When running via a local [`Client`](crate::local::asynchronous::Client),
+    /// there is a single, custom endpoint which does not have a socket address.
+    pub fn endpoints(&self) -> &[Endpoint] {
+        &self.endpoints
+    }
+
+    /// Returns the profile the configuration was extracted from.
+    pub fn profile(&self) -> &Profile {
+        &self.profile
+    }
+
+    /// Returns the number of worker threads configured via
+    /// [`Config::workers`](crate::Config::workers).
+    pub fn workers(&self) -> usize {
+        self.workers
+    }
+
+    /// Returns the wall-clock time at which the instance entered orbit.
+    pub fn start_time(&self) -> SystemTime {
+        self.start_time
+    }
+}
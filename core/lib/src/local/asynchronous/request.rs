@@ -85,8 +85,10 @@ impl<'c> LocalRequest<'c> {
             // _shouldn't_ error. Check that now and error only if not.
             if self.inner().uri() == invalid {
                 error!("invalid request URI: {:?}", invalid.path());
-                return LocalResponse::new(self.request, move |req| {
-                    rocket.dispatch_error(Status::BadRequest, req)
+                return LocalResponse::new(self.request, move |req| async move {
+                    let mut response = rocket.dispatch_error(Status::BadRequest, req).await;
+                    rocket.set_server_timing(req, &mut response, None);
+                    response
                 }).await
             }
         }
@@ -2,6 +2,7 @@
 
 use std::fmt;
 use std::str::FromStr;
+use std::sync::Once;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use serde::{de, Serialize, Serializer, Deserialize, Deserializer};
@@ -156,6 +157,10 @@ impl log::Log for RocketLogger {
 
 static ROCKET_LOGGER_SET: AtomicBool = AtomicBool::new(false);
 
+static PANIC_HOOK_SET: Once = Once::new();
+
+static LOG_PANICS: AtomicBool = AtomicBool::new(false);
+
 pub(crate) fn init_default() {
     if !ROCKET_LOGGER_SET.load(Ordering::Acquire) {
         crate::log::init(&crate::Config::debug_default())
@@ -181,6 +186,43 @@ pub(crate) fn init(config: &crate::Config) {
     if ROCKET_LOGGER_SET.load(Ordering::Acquire) {
         log::set_max_level(config.log_level.into());
     }
+
+    // The hook is installed once but consults the most recent configuration.
+    LOG_PANICS.store(config.log_panics, Ordering::Release);
+    if config.log_panics {
+        PANIC_HOOK_SET.call_once(install_panic_hook);
+    }
+}
+
+// Logs panics via `log`, if enabled, before chaining to the previous hook.
+fn install_panic_hook() {
+    use std::backtrace::{Backtrace, BacktraceStatus};
+
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if !LOG_PANICS.load(Ordering::Acquire) {
+            return previous(info);
+        }
+
+        let payload = info.payload();
+        let message = payload.downcast_ref::<&str>().copied()
+            .or_else(|| payload.downcast_ref::<String>().map(|s| s.as_str()))
+            .unwrap_or("Box<dyn Any>");
+
+        let thread = std::thread::current();
+        let thread = thread.name().unwrap_or("<unnamed>");
+        log::error!(target: "rocket::panic", "thread '{}' panicked: {}", thread, message);
+        if let Some(location) = info.location() {
+            log::error!(target: "rocket::panic::_", "at {}", location);
+        }
+
+        let backtrace = Backtrace::capture();
+        if backtrace.status() == BacktraceStatus::Captured {
+            log::error!(target: "rocket::panic::_", "backtrace:\n{}", backtrace);
+        }
+
+        previous(info);
+    }));
 }
 
 impl From<LogLevel> for log::LevelFilter {
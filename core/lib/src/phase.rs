@@ -1,11 +1,15 @@
+use std::sync::Arc;
+
 use state::TypeMap;
 use figment::Figment;
+use parking_lot::Mutex;
 
-use crate::listener::Endpoint;
+use crate::LiftoffInfo;
 use crate::shutdown::Stages;
 use crate::{Catcher, Config, Rocket, Route};
 use crate::router::Router;
 use crate::fairing::Fairings;
+use crate::error::PreflightReport;
 
 mod private {
     pub trait Sealed {  }
@@ -86,6 +90,7 @@ phases! {
         pub(crate) fairings: Fairings,
         pub(crate) figment: Figment,
         pub(crate) state: TypeMap![Send + Sync],
+        pub(crate) preflight: Arc<Mutex<PreflightReport>>,
     }
 
     /// The second launch [`Phase`]: post-build but pre-orbit. See
@@ -115,6 +120,6 @@ phases! {
         pub(crate) config: Config,
         pub(crate) state: TypeMap![Send + Sync],
         pub(crate) shutdown: Stages,
-        pub(crate) endpoints: Vec<Endpoint>,
+        pub(crate) liftoff: LiftoffInfo,
     }
 }
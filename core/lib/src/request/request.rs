@@ -771,6 +771,23 @@ impl<'r> Request<'r> {
     /// invoked. This method returns `None` _before_ routing has commenced; this
     /// includes during request fairing callbacks.
     ///
+    /// More precisely, the following guarantees hold:
+    ///
+    ///   * The route is set immediately before a matching route's guards and
+    ///     handler are invoked, and is the route whose guards and handler are
+    ///     being invoked.
+    ///   * When a route forwards, the route is replaced by the next matching
+    ///     route, if any, before that route's guards are invoked.
+    ///   * After routing, including in catchers and response fairings, the
+    ///     route is the _last_ route that was attempted: the route that
+    ///     succeeded or failed or, if all matching routes forwarded, the last
+    ///     one to forward.
+    ///   * If no route matches the request, the route remains `None`.
+    ///
+    /// As such, the route pattern, for instance [`Route::uri`], can be used in
+    /// guards, handlers, catchers, and response fairings to identify which
+    /// route handled a request independently of the concrete request path.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -0,0 +1,174 @@
+use std::fmt;
+use std::borrow::Cow;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request-local phase timings reported via the `Server-Timing` header.
+///
+/// When [`Config::server_timing`](crate::Config::server_timing) is enabled,
+/// Rocket records the total time spent processing a request as `total`, and
+/// the time spent routing and running route guards, handlers, and catchers as
+/// `handler`. `total` is measured from when Rocket begins processing the
+/// request, before the `_method` form field is read and request fairings run,
+/// until response fairings have run. These, along with any custom entries added
+/// via [`Timings::add()`], are emitted in a [`Server-Timing`] response header:
+///
+/// ```text
+/// Server-Timing: db;dur=3.104, handler;dur=9.820, total;dur=12.311
+/// ```
+///
+/// Durations are measured in milliseconds using a monotonic clock. The header
+/// is not emitted for informational (`1xx`) responses nor in response to
+/// requests which ask to be upgraded. Requests which are rejected as malformed
+/// before routing receive a `total` but no `handler` entry.
+///
+/// _**Note:** The header is set after response fairings run. As such,
+/// response fairings cannot observe or modify it, but entries they add are
+/// included._
+///
+/// `Timings` is a request guard that always succeeds. Handlers, guards, and
+/// fairings can retrieve the request's `Timings` via [`Timings::of()`] and add
+/// custom entries to it. Entries can be added irrespective of whether
+/// `server_timing` is enabled; they are simply not emitted when it isn't.
+///
+/// [`Server-Timing`]: https://www.w3.org/TR/server-timing/
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use std::time::Instant;
+/// use rocket::request::Timings;
+///
+/// #[get("/")]
+/// fn index(timings: &Timings) -> &'static str {
+///     let start = Instant::now();
+///     // query the database...
+///     timings.add("db", start.elapsed());
+///     "Hello, world!"
+/// }
+/// ```
+pub struct Timings {
+    start: Instant,
+    entries: Mutex<Vec<(Cow<'static, str>, Duration)>>,
+}
+
+impl Timings {
+    /// Returns the `Timings` for `request`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::request::Timings;
+    ///
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// Timings::of(&request).add("cache", Duration::from_micros(250));
+    /// ```
+    pub fn of<'r>(request: &'r Request<'_>) -> &'r Timings {
+        request.local_cache(Timings::default)
+    }
+
+    /// Adds an entry named `name` with duration `duration`. Adding an entry
+    /// with the same name as an existing entry replaces the existing entry.
+    ///
+    /// `name` must be a valid HTTP token, that is, nonempty and consisting of
+    /// only alphanumeric ASCII characters and one of ``!#$%&'*+-.^_`|~``. An
+    /// entry with an invalid name is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::request::Timings;
+    ///
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// let timings = Timings::of(&request);
+    /// timings.add("db", Duration::from_millis(3));
+    /// assert_eq!(timings.get("db"), Some(Duration::from_millis(3)));
+    ///
+    /// timings.add("not valid", Duration::from_millis(1));
+    /// assert_eq!(timings.get("not valid"), None);
+    /// ```
+    pub fn add<N: Into<Cow<'static, str>>>(&self, name: N, duration: Duration) {
+        let name = name.into();
+        if !is_token(&name) {
+            return;
+        }
+
+        let mut entries = self.entries.lock();
+        match entries.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = duration,
+            None => entries.push((name, duration)),
+        }
+    }
+
+    /// Returns the duration of the entry named `name`, if there is one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::request::Timings;
+    ///
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// let timings = Timings::of(&request);
+    /// assert_eq!(timings.get("db"), None);
+    /// ```
+    pub fn get(&self, name: &str) -> Option<Duration> {
+        self.entries.lock().iter().find(|(n, _)| n == name).map(|(_, d)| *d)
+    }
+
+    /// Returns the time elapsed since `self` was created.
+    pub(crate) fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+impl Default for Timings {
+    fn default() -> Self {
+        Timings { start: Instant::now(), entries: Mutex::default() }
+    }
+}
+
+fn is_token(name: &str) -> bool {
+    !name.is_empty() && name.bytes().all(|b| b.is_ascii_alphanumeric()
+        || b"!#$%&'*+-.^_`|~".contains(&b))
+}
+
+impl fmt::Display for Timings {
+    /// Formats `self` as the value of a `Server-Timing` header.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, duration)) in self.entries.lock().iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+
+            let millis = duration.as_secs_f64() * 1000.0;
+            write!(f, "{};dur={:.3}", name, millis)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.entries.lock().iter()).finish()
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for &'r Timings {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(Timings::of(req))
+    }
+}
@@ -11,7 +11,8 @@ use figment::{Figment, Provider};
 use futures::TryFutureExt;
 
 use crate::shutdown::{Stages, Shutdown};
-use crate::{sentinel, shield::Shield, Catcher, Config, Route};
+use crate::config::StartupSummary;
+use crate::{sentinel, shield::Shield, Catcher, Config, LiftoffInfo, Route};
 use crate::listener::{Bind, DefaultListener, Endpoint, Listener};
 use crate::router::Router;
 use crate::fairing::{Fairing, Fairings};
@@ -19,7 +20,7 @@ use crate::phase::{Phase, Build, Building, Ignite, Igniting, Orbit, Orbiting};
 use crate::phase::{Stateful, StateRef, State};
 use crate::http::uri::Origin;
 use crate::http::ext::IntoOwned;
-use crate::error::{Error, ErrorKind};
+use crate::error::{Diagnostic, Error, ErrorKind, PreflightReport};
 use crate::log::PaintExt;
 
 /// The application server itself.
@@ -554,10 +555,6 @@ impl Rocket<Build> {
                 config.secret_key = crate::config::SecretKey::generate()
                     .unwrap_or_else(crate::config::SecretKey::zero);
             }
-        } else if config.known_secret_key_used() {
-            warn!("The configured `secret_key` is exposed and insecure.");
-            warn_!("The configured key is publicly published and thus insecure.");
-            warn_!("Try generating a new key with `head -c64 /dev/urandom | base64`.");
         }
 
         // Initialize the router; check for collisions.
@@ -572,9 +569,11 @@ impl Rocket<Build> {
         // Log everything we know: config, routes, catchers, fairings.
         // TODO: Store/print managed state type names?
         config.pretty_print(self.figment());
-        log_items("📬 ", "Routes", self.routes(), |r| &r.uri.base, |r| &r.uri);
-        log_items("🥅 ", "Catchers", self.catchers(), |c| &c.base, |c| &c.base);
-        self.fairings.pretty_print();
+        if config.startup_summary.is_full(config.log_level) {
+            log_items("📬 ", "Routes", self.routes(), |r| &r.uri.base, |r| &r.uri);
+            log_items("🥅 ", "Catchers", self.catchers(), |c| &c.base, |c| &c.base);
+            self.fairings.pretty_print();
+        }
 
         // Ignite the rocket.
         let rocket: Rocket<Ignite> = Rocket(Igniting {
@@ -591,6 +590,107 @@ impl Rocket<Build> {
 
         Ok(rocket)
     }
+
+    /// Records a warning with the stable `code` and `message` to be included
+    /// in the [`PreflightReport`] returned by [`Rocket::preflight()`].
+    ///
+    /// This is intended for use by ignite fairings, including those in
+    /// libraries, which detect misconfigurations that don't prevent launch.
+    /// Outside of [`Rocket::preflight()`], recorded warnings are discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// # rocket::async_test(async {
+    /// let rocket = rocket::custom(rocket::Config::debug_default())
+    ///     .attach(AdHoc::on_ignite("Cache", |rocket| async move {
+    ///         rocket.preflight_warning("cache-disabled", "no cache size configured")
+    ///     }));
+    ///
+    /// let report = rocket.preflight().await;
+    /// assert_eq!(report.warnings[0].code, "cache-disabled");
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn preflight_warning<M: Into<String>>(self, code: &'static str, message: M) -> Self {
+        self.0.preflight.lock().warnings.push(Diagnostic::new(code, message));
+        self
+    }
+
+    /// Records an error with the stable `code` and `message` to be included
+    /// in the [`PreflightReport`] returned by [`Rocket::preflight()`].
+    ///
+    /// An ignite fairing which fails can use this to report _why_ it failed;
+    /// the failure itself is reported separately as `failed-fairings`.
+    /// Outside of [`Rocket::preflight()`], recorded errors are discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// # rocket::async_test(async {
+    /// let rocket = rocket::custom(rocket::Config::debug_default())
+    ///     .attach(AdHoc::try_on_ignite("Database", |rocket| async move {
+    ///         Err(rocket.preflight_error("database", "`database.url` is missing"))
+    ///     }));
+    ///
+    /// let report = rocket.preflight().await;
+    /// let codes: Vec<_> = report.errors.iter().map(|e| e.code).collect();
+    /// assert_eq!(codes, ["database", "failed-fairings"]);
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn preflight_error<M: Into<String>>(self, code: &'static str, message: M) -> Self {
+        self.0.preflight.lock().errors.push(Diagnostic::new(code, message));
+        self
+    }
+
+    /// Ignites `self` as [`Rocket::ignite()`] does, without launching, and
+    /// reports every error and warning detected in the process.
+    ///
+    /// Ignition runs ignite fairings, extracts and validates the
+    /// configuration, checks for route and catcher collisions, and queries
+    /// sentinels. Any failure is recorded as an error in the returned
+    /// [`PreflightReport`]. Warnings about deprecated configuration keys and
+    /// profiles are always recorded. If ignition succeeds, warnings about
+    /// volatile or insecure secret keys are recorded as well. If it fails,
+    /// deprecations are read from the configuration figment as it was before
+    /// ignite fairings ran. This is useful to fail a CI build on a
+    /// misconfigured application without starting a server.
+    ///
+    /// Errors and warnings recorded during ignition via
+    /// [`Rocket::preflight_error()`] and [`Rocket::preflight_warning()`], by
+    /// ignite fairings for instance, precede those detected by Rocket.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// #[rocket::main]
+    /// async fn main() {
+    ///     let report = rocket::build().preflight().await;
+    ///     if !report.is_clean() {
+    ///         report.errors.iter().for_each(|e| eprintln!("error: {}", e));
+    ///         report.warnings.iter().for_each(|w| eprintln!("warning: {}", w));
+    ///         std::process::exit(1);
+    ///     }
+    /// }
+    /// ```
+    pub async fn preflight(self) -> PreflightReport {
+        let figment = self.figment().clone();
+        let recorded = self.0.preflight.clone();
+        let (warnings, error) = match self.ignite().await {
+            Ok(rocket) => (rocket.config.diagnostics(rocket.figment()), None),
+            Err(e) => (Config::deprecation_diagnostics(&figment), Some(e)),
+        };
+
+        let mut report = std::mem::take(&mut *recorded.lock());
+        report.warnings.extend(warnings);
+        report.errors.extend(error.map(Diagnostic::from));
+        report
+    }
 }
 
 fn log_items<T, I, B, O>(e: &str, t: &str, items: I, base: B, origin: O)
@@ -664,8 +764,9 @@ impl Rocket<Ignite> {
     }
 
     pub(crate) fn into_orbit(self, endpoints: Vec<Endpoint>) -> Rocket<Orbit> {
+        let liftoff = LiftoffInfo::new(endpoints, self.config.profile.clone(), self.config.workers);
         Rocket(Orbiting {
-            endpoints,
+            liftoff,
             router: self.0.router,
             fairings: self.0.fairings,
             figment: self.0.figment,
@@ -766,9 +867,17 @@ impl Rocket<Orbit> {
             info_!("Forced shutdown is disabled. Runtime settings may be suboptimal.");
         }
 
-        launch_info!("{}{} {}", "🚀 ".emoji(),
-            "Rocket has launched on".bold().primary().linger(),
-            rocket.endpoints[0].underline());
+        let info = rocket.liftoff_info();
+        match rocket.config.startup_summary {
+            StartupSummary::Full => launch_info!("{}{} {}", "🚀 ".emoji(),
+                "Rocket has launched on".bold().primary().linger(),
+                info.endpoints()[0].underline()),
+            StartupSummary::Compact => launch_info!(
+                "rocket launched addr={} routes={} catchers={} fairings={} profile={}",
+                info.endpoints()[0], rocket.routes().count(), rocket.catchers().count(),
+                rocket.fairings.active_count(), info.profile()),
+            StartupSummary::Quiet => {}
+        }
     }
 
     /// Returns the finalized, active configuration. This is guaranteed to
@@ -794,7 +903,30 @@ impl Rocket<Orbit> {
     }
 
     pub fn endpoints(&self) -> impl Iterator<Item = &Endpoint> {
-        self.endpoints.iter()
+        self.liftoff.endpoints().iter()
+    }
+
+    /// Returns the [`LiftoffInfo`] for this instance, including all bound
+    /// endpoints. The info is available to and unchanged from the moment
+    /// liftoff fairings run.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build()
+    ///         .attach(AdHoc::on_liftoff("Info", |rocket| Box::pin(async move {
+    ///             let info = rocket.liftoff_info();
+    ///             println!("{} workers on {:?}", info.workers(), info.endpoints());
+    ///         })))
+    /// }
+    /// ```
+    pub fn liftoff_info(&self) -> &LiftoffInfo {
+        &self.liftoff
     }
 
     /// Returns a handle which can be used to trigger a shutdown and detect a
@@ -37,7 +37,9 @@ impl Rocket<Orbit> {
             |rocket, request, data| Box::pin(rocket.preprocess(request, data)),
             |token, rocket, request, data| Box::pin(async move {
                 if !request.errors.is_empty() {
-                    return rocket.dispatch_error(Status::BadRequest, request).await;
+                    let mut response = rocket.dispatch_error(Status::BadRequest, request).await;
+                    rocket.set_server_timing(request, &mut response, None);
+                    return response;
                 }
 
                 rocket.dispatch(token, request, data).await
@@ -138,7 +140,7 @@ impl Rocket<Orbit> {
               L::Connection: AsyncRead + AsyncWrite
     {
         let mut builder = Builder::new(TokioExecutor::new());
-        let keep_alive = Duration::from_secs(self.config.keep_alive.into());
+        let keep_alive: Duration = self.config.keep_alive.into();
         builder.http1()
             .half_close(true)
             .timer(TokioTimer::new())
@@ -4,6 +4,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use yansi::Paint;
 
 use crate::{Rocket, Request, Response, Orbit, Config};
+use crate::config::StartupSummary;
 use crate::fairing::{Fairing, Info, Kind};
 use crate::http::{Header, uncased::UncasedStr};
 use crate::log::PaintExt;
@@ -195,11 +196,14 @@ impl Fairing for Shield {
             self.force_hsts.store(true, Ordering::Release);
         }
 
-        if !self.policies.is_empty() {
-            info!("{}{}:", "🛡️ ".emoji(), "Shield".magenta());
+        let summary = rocket.config().startup_summary;
+        if !self.policies.is_empty() && summary != StartupSummary::Quiet {
+            if summary.is_full(rocket.config().log_level) {
+                info!("{}{}:", "🛡️ ".emoji(), "Shield".magenta());
 
-            for header in self.policies.values() {
-                info_!("{}: {}", header.name(), header.value().primary());
+                for header in self.policies.values() {
+                    info_!("{}: {}", header.name(), header.value().primary());
+                }
             }
 
             if force_hsts {
@@ -1,4 +1,4 @@
-use std::{fmt, time::Duration};
+use std::fmt;
 
 #[cfg(unix)]
 use std::collections::HashSet;
@@ -7,6 +7,7 @@ use futures::stream::Stream;
 use serde::{Deserialize, Serialize};
 
 use crate::shutdown::Sig;
+use crate::config::Duration;
 
 /// Graceful shutdown configuration.
 ///
@@ -18,13 +19,14 @@ use crate::shutdown::Sig;
 ///
 /// When a shutdown is triggered by an externally or internally initiated
 /// [`Shutdown::notify()`], Rocket allows application I/O to make progress for
-/// at most `grace` seconds before initiating connection-level shutdown.
-/// Connection shutdown forcibly terminates _application_ I/O, but connections
-/// are allowed an additional `mercy` seconds to shutdown before being
-/// forcefully terminated. This implies that a _cooperating_ and active remote
-/// client maintaining an open connection can stall shutdown for at most `grace`
-/// seconds, while an _uncooperative_ remote client can stall shutdown for at
-/// most `grace + mercy` seconds.
+/// at most `grace` before initiating connection-level shutdown. Connection
+/// shutdown forcibly terminates _application_ I/O, but connections are allowed
+/// an additional `mercy` to shutdown before being forcefully terminated. This
+/// implies that a _cooperating_ and active remote client maintaining an open
+/// connection can stall shutdown for at most `grace`, while an _uncooperative_
+/// remote client can stall shutdown for at most `grace + mercy`. Both periods
+/// are [`Duration`]s and can be configured as an integer number of seconds or
+/// as a string such as `"500ms"` or `"1m"`.
 ///
 /// # Triggers
 ///
@@ -40,7 +42,7 @@ use crate::shutdown::Sig;
 /// # Grace Period
 ///
 /// Once a shutdown is triggered, Rocket stops accepting new connections and
-/// waits at most `grace` seconds before initiating connection shutdown.
+/// waits at most `grace` before initiating connection shutdown.
 /// Applications can `await` the [`Shutdown`] future to detect
 /// a shutdown and cancel any server-initiated I/O, such as from [infinite
 /// responders](crate::response::stream#graceful-shutdown), to avoid abrupt I/O
@@ -52,7 +54,7 @@ use crate::shutdown::Sig;
 ///
 /// After the grace period has elapsed, Rocket initiates connection shutdown,
 /// allowing connection-level I/O termination such as TLS's `close_notify` to
-/// proceed nominally. Rocket waits at most `mercy` seconds for connections to
+/// proceed nominally. Rocket waits at most `mercy` for connections to
 /// shutdown before forcefully terminating all connections.
 ///
 /// # Runaway I/O
@@ -87,7 +89,7 @@ use crate::shutdown::Sig;
 ///
 /// ```rust
 /// # use rocket::figment::{Figment, providers::{Format, Toml}};
-/// use rocket::Config;
+/// use rocket::config::{Config, Duration};
 ///
 /// // If these are the contents of `Rocket.toml`...
 /// # let toml = Toml::string(r#"
@@ -95,15 +97,15 @@ use crate::shutdown::Sig;
 /// ctrlc = false
 /// signals = ["term", "hup"]
 /// grace = 10
-/// mercy = 5
+/// mercy = "5s"
 /// # force = false
 /// # "#).nested();
 ///
 /// // The config parses as follows:
 /// # let config = Config::from(Figment::from(Config::debug_default()).merge(toml));
 /// assert_eq!(config.shutdown.ctrlc, false);
-/// assert_eq!(config.shutdown.grace, 10);
-/// assert_eq!(config.shutdown.mercy, 5);
+/// assert_eq!(config.shutdown.grace, Duration::from_secs(10));
+/// assert_eq!(config.shutdown.mercy, Duration::from_secs(5));
 /// # assert_eq!(config.shutdown.force, false);
 ///
 /// # #[cfg(unix)] {
@@ -119,7 +121,7 @@ use crate::shutdown::Sig;
 ///
 /// ```rust
 /// # use rocket::figment::{Figment, providers::{Format, Toml}};
-/// use rocket::config::{Config, ShutdownConfig};
+/// use rocket::config::{Config, ShutdownConfig, Duration};
 ///
 /// #[cfg(unix)]
 /// use rocket::config::Sig;
@@ -134,8 +136,8 @@ use crate::shutdown::Sig;
 ///             set.insert(Sig::Hup);
 ///             set
 ///         },
-///         grace: 10,
-///         mercy: 5,
+///         grace: Duration::from_secs(10),
+///         mercy: Duration::from_secs(5),
 ///         force: true,
 ///         ..Default::default()
 ///     },
@@ -143,8 +145,8 @@ use crate::shutdown::Sig;
 /// };
 ///
 /// assert_eq!(config.shutdown.ctrlc, false);
-/// assert_eq!(config.shutdown.grace, 10);
-/// assert_eq!(config.shutdown.mercy, 5);
+/// assert_eq!(config.shutdown.grace, Duration::from_secs(10));
+/// assert_eq!(config.shutdown.mercy, Duration::from_secs(5));
 /// assert_eq!(config.shutdown.force, true);
 ///
 /// #[cfg(unix)] {
@@ -167,16 +169,16 @@ pub struct ShutdownConfig {
     #[cfg(unix)]
     #[cfg_attr(nightly, doc(cfg(unix)))]
     pub signals: HashSet<Sig>,
-    /// The grace period: number of seconds to continue to try to finish
-    /// outstanding _server_ I/O for before forcibly terminating it.
+    /// The grace period: how long to continue to try to finish outstanding
+    /// _server_ I/O for before forcibly terminating it.
     ///
-    /// **default: `2`**
-    pub grace: u32,
-    /// The mercy period: number of seconds to continue to try to finish
-    /// outstanding _connection_ I/O for before forcibly terminating it.
+    /// **default: `2s`**
+    pub grace: Duration,
+    /// The mercy period: how long to continue to try to finish outstanding
+    /// _connection_ I/O for before forcibly terminating it.
     ///
-    /// **default: `3`**
-    pub mercy: u32,
+    /// **default: `3s`**
+    pub mercy: Duration,
     /// Whether to force termination of an async runtime that refuses to
     /// cooperatively shutdown.
     ///
@@ -195,11 +197,11 @@ pub struct ShutdownConfig {
     /// _always_ be done using a public constructor or update syntax:
     ///
     /// ```rust
-    /// use rocket::config::ShutdownConfig;
+    /// use rocket::config::{ShutdownConfig, Duration};
     ///
     /// let config = ShutdownConfig {
-    ///     grace: 5,
-    ///     mercy: 10,
+    ///     grace: Duration::from_secs(5),
+    ///     mercy: Duration::from_secs(10),
     ///     ..Default::default()
     /// };
     /// ```
@@ -221,7 +223,7 @@ impl fmt::Display for ShutdownConfig {
             write!(f, "], ")?;
         }
 
-        write!(f, "grace = {}s, mercy = {}s", self.grace, self.mercy)?;
+        write!(f, "grace = {}, mercy = {}", self.grace, self.mercy)?;
         Ok(())
     }
 }
@@ -232,8 +234,8 @@ impl Default for ShutdownConfig {
             ctrlc: true,
             #[cfg(unix)]
             signals: { let mut set = HashSet::new(); set.insert(Sig::Term); set },
-            grace: 2,
-            mercy: 3,
+            grace: Duration::from_secs(2),
+            mercy: Duration::from_secs(3),
             force: true,
             __non_exhaustive: (),
         }
@@ -241,12 +243,12 @@ impl Default for ShutdownConfig {
 }
 
 impl ShutdownConfig {
-    pub(crate) fn grace(&self) -> Duration {
-        Duration::from_secs(self.grace as u64)
+    pub(crate) fn grace(&self) -> std::time::Duration {
+        self.grace.into()
     }
 
-    pub(crate) fn mercy(&self) -> Duration {
-        Duration::from_secs(self.mercy as u64)
+    pub(crate) fn mercy(&self) -> std::time::Duration {
+        self.mercy.into()
     }
 
     #[cfg(unix)]
@@ -0,0 +1,66 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Cookie, CookieJar};
+
+#[get("/login")]
+fn login(jar: &CookieJar<'_>) {
+    jar.add(("auth_user", "bob"));
+    jar.add(("theme", "dark"));
+}
+
+#[get("/admin/login")]
+fn admin_login(jar: &CookieJar<'_>) {
+    jar.add(Cookie::build(("auth_scope", "admin")).path("/admin"));
+}
+
+#[get("/logout")]
+fn logout(jar: &CookieJar<'_>) {
+    jar.add(Cookie::build(("auth_fresh", "1")).path("/account"));
+    jar.remove_matching("auth_");
+}
+
+#[get("/admin/logout")]
+fn admin_logout(jar: &CookieJar<'_>) {
+    jar.add(Cookie::build(("auth_scope", "refreshed")).path("/admin"));
+    jar.remove_matching("auth_");
+}
+
+mod cookies_remove_matching_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    fn rocket() -> rocket::Rocket<rocket::Build> {
+        rocket::build().mount("/", routes![login, logout, admin_login, admin_logout])
+    }
+
+    #[test]
+    fn removes_only_matching_cookies() {
+        let client = Client::debug(rocket()).unwrap();
+        client.get("/login").dispatch();
+
+        let response = client.get("/logout").dispatch();
+        let cookies = response.cookies();
+        assert_eq!(cookies.iter().count(), 1);
+
+        let auth_user = cookies.get("auth_user").unwrap();
+        assert_eq!(auth_user.value(), "");
+        assert_eq!(auth_user.path(), Some("/"));
+        assert!(cookies.get("auth_fresh").is_none());
+        assert!(cookies.get("theme").is_none());
+
+        let jar = client.cookies();
+        assert!(jar.get("auth_user").is_none());
+        assert_eq!(jar.get("theme").unwrap().value(), "dark");
+    }
+
+    #[test]
+    fn removal_uses_path_of_pending_addition() {
+        let client = Client::debug(rocket()).unwrap();
+        client.get("/admin/login").dispatch();
+
+        let response = client.get("/admin/logout").dispatch();
+        let auth_scope = response.cookies().get("auth_scope").unwrap();
+        assert_eq!(auth_scope.value(), "");
+        assert_eq!(auth_scope.path(), Some("/admin"));
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::{Arc, Mutex};
+
+use rocket::{Rocket, Build, LiftoffInfo};
+use rocket::fairing::AdHoc;
+use rocket::figment::Figment;
+use rocket::local::asynchronous::Client;
+
+fn recorder(rocket: Rocket<Build>) -> (Rocket<Build>, Arc<Mutex<Option<LiftoffInfo>>>) {
+    let info = Arc::new(Mutex::new(None));
+    let recorded = info.clone();
+    let rocket = rocket.attach(AdHoc::on_liftoff("Record", move |rocket| {
+        let recorded = recorded.clone();
+        Box::pin(async move {
+            let info = rocket.liftoff_info();
+            assert!(rocket.endpoints().eq(info.endpoints()));
+            if let Some(addr) = info.endpoints()[0].tcp() {
+                std::net::TcpStream::connect(addr).expect("endpoint is bound");
+            }
+
+            *recorded.lock().unwrap() = Some(info.clone());
+            rocket.shutdown().notify();
+        })
+    }));
+
+    (rocket, info)
+}
+
+fn figment() -> Figment {
+    rocket::Config::figment()
+        .merge(("address", "127.0.0.1"))
+        .merge(("port", 0))
+        .merge(("workers", 3))
+        .merge(("startup_summary", "quiet"))
+}
+
+#[rocket::async_test]
+async fn liftoff_info_tcp() {
+    let (rocket, info) = recorder(rocket::custom(figment()));
+    rocket.launch().await.unwrap();
+
+    let info = info.lock().unwrap().take().expect("liftoff fairing ran");
+    assert_eq!(info.endpoints().len(), 1);
+    assert!(!info.endpoints()[0].is_tls());
+    assert_ne!(info.endpoints()[0].port(), Some(0));
+    assert_eq!(info.profile(), figment().profile());
+    assert_eq!(info.workers(), 3);
+    assert!(info.start_time() <= std::time::SystemTime::now());
+}
+
+#[rocket::async_test]
+async fn liftoff_info_local_client() {
+    let (rocket, info) = recorder(rocket::custom(figment()));
+    let client = Client::debug(rocket).await.unwrap();
+
+    let info = info.lock().unwrap().take().expect("liftoff fairing ran");
+    assert_eq!(info.endpoints().len(), 1);
+    assert!(info.endpoints()[0].socket_addr().is_none());
+    assert!(client.rocket().liftoff_info().endpoints()[0].socket_addr().is_none());
+}
+
+#[cfg(feature = "tls")]
+#[rocket::async_test]
+async fn liftoff_info_tls() {
+    use rocket::fs::relative;
+
+    let figment = figment()
+        .merge(("tls.certs", relative!("../../examples/tls/private/rsa_sha256_cert.pem")))
+        .merge(("tls.key", relative!("../../examples/tls/private/rsa_sha256_key.pem")));
+
+    let (rocket, info) = recorder(rocket::custom(figment));
+    rocket.launch().await.unwrap();
+
+    let info = info.lock().unwrap().take().expect("liftoff fairing ran");
+    assert!(info.endpoints()[0].is_tls());
+    assert_ne!(info.endpoints()[0].port(), Some(0));
+}
+
+#[cfg(unix)]
+#[rocket::async_test]
+async fn liftoff_info_unix() {
+    use rocket::listener::unix::UnixListener;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("rocket.sock");
+    let (rocket, info) = recorder(rocket::custom(figment()));
+    rocket.try_launch_on(UnixListener::bind(&path, false)).await.unwrap();
+
+    let info = info.lock().unwrap().take().expect("liftoff fairing ran");
+    assert_eq!(info.endpoints()[0].unix(), Some(&*path));
+}
@@ -0,0 +1,126 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::{Mutex, Once};
+
+use rocket::{Config, Request};
+use rocket::fairing::AdHoc;
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+static RECORDS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+struct Recorder;
+
+impl log::Log for Recorder {
+    fn enabled(&self, _: &log::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        let record = (record.target().to_string(), record.args().to_string());
+        RECORDS.lock().unwrap().push(record);
+    }
+
+    fn flush(&self) { }
+}
+
+/// Runs `f` with `Recorder` installed as the global logger and returns the
+/// `(target, message)` of every record logged while it ran. Calls are
+/// serialized so that records from concurrent tests don't intermingle.
+fn capture<F: FnOnce()>(f: F) -> Vec<(String, String)> {
+    static INSTALL: Once = Once::new();
+    static SERIAL: Mutex<()> = Mutex::new(());
+
+    INSTALL.call_once(|| {
+        log::set_logger(&Recorder).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+    });
+
+    let _guard = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    RECORDS.lock().unwrap().clear();
+    f();
+    std::mem::take(&mut *RECORDS.lock().unwrap())
+}
+
+#[get("/")]
+fn index() { }
+
+#[get("/panic")]
+fn panics() {
+    panic!("boom");
+}
+
+#[catch(404)]
+fn not_found(_: &Request<'_>) { }
+
+fn panic_records(log_panics: bool) -> Vec<String> {
+    let records = capture(|| {
+        let config = Config { log_panics, ..Config::debug_default() };
+        let client = Client::debug(rocket::custom(config).mount("/", routes![panics])).unwrap();
+        assert_eq!(client.get("/panic").dispatch().status(), Status::InternalServerError);
+    });
+
+    records.into_iter()
+        .filter(|(target, _)| target.starts_with("rocket::panic"))
+        .map(|(_, message)| message)
+        .collect()
+}
+
+#[test]
+fn log_panics_respects_config() {
+    let records = panic_records(true);
+    assert!(records.iter().any(|m| m.contains("panicked: boom")), "{:?}", records);
+    assert!(records.iter().any(|m| m.starts_with("at ") && m.contains("log-capture.rs")));
+
+    let records = panic_records(false);
+    assert!(records.is_empty(), "{:?}", records);
+
+    let records = panic_records(true);
+    assert!(!records.is_empty());
+}
+
+fn launch_records(summary: &str) -> Vec<(String, String)> {
+    capture(|| {
+        let key = "Bx4Gb+aSIfuoEyMHD4DvNs92+wmzfQK98qc6MiwyPY4=";
+        let figment = Config::figment()
+            .merge(("secret_key", key))
+            .merge(("log_level", "normal"))
+            .merge(("cli_colors", false))
+            .merge(("startup_summary", summary));
+
+        let rocket = rocket::custom(figment)
+            .mount("/", routes![index])
+            .register("/", catchers![not_found])
+            .attach(AdHoc::on_request("Noop", |_, _| Box::pin(async {})));
+
+        let _client = Client::untracked(rocket).unwrap();
+    })
+}
+
+fn is_launch(target: &str) -> bool {
+    target == "rocket::launch" || target == "rocket::launch::_"
+}
+
+fn is_listing((target, message): &(String, String)) -> bool {
+    target.starts_with("rocket::shield")
+        || ["Routes", "Catchers", "Fairings"].iter().any(|h| message.contains(h))
+}
+
+#[test]
+fn startup_summary_modes() {
+    let records = launch_records("full");
+    assert!(records.iter().any(|r| r.1.contains("Routes")), "{:?}", records);
+    assert!(records.iter().any(|r| r.0.starts_with("rocket::shield")), "{:?}", records);
+    assert!(records.iter().any(|r| r.1.contains("Rocket has launched on")), "{:?}", records);
+
+    let records = launch_records("compact");
+    let launch: Vec<_> = records.iter().filter(|r| is_launch(&r.0)).collect();
+    assert_eq!(launch.len(), 1, "{:?}", records);
+    assert!(launch[0].1.starts_with("rocket launched addr="), "{:?}", launch);
+    assert!(launch[0].1.contains("routes=1 catchers=1"), "{:?}", launch);
+    assert!(!records.iter().any(is_listing), "{:?}", records);
+
+    let records = launch_records("quiet");
+    assert!(!records.iter().any(|r| is_launch(&r.0)), "{:?}", records);
+    assert!(!records.iter().any(is_listing), "{:?}", records);
+}
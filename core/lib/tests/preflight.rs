@@ -0,0 +1,87 @@
+#[macro_use] extern crate rocket;
+
+use rocket::Config;
+use rocket::fairing::AdHoc;
+use rocket::figment::Figment;
+
+#[get("/")]
+fn index() { }
+
+#[get("/")]
+fn other_index() { }
+
+fn figment() -> Figment {
+    let key = "Bx4Gb+aSIfuoEyMHD4DvNs92+wmzfQK98qc6MiwyPY4=";
+    Figment::from(Config::debug_default()).merge(("secret_key", key))
+}
+
+#[rocket::async_test]
+async fn preflight_clean() {
+    let report = rocket::custom(figment()).mount("/", routes![index]).preflight().await;
+    assert!(report.is_clean(), "{:?}", report);
+}
+
+#[rocket::async_test]
+async fn preflight_deprecated_key() {
+    let rocket = rocket::custom(figment().merge(("read_timeout", 5)));
+    let report = rocket.preflight().await;
+    assert!(report.errors.is_empty());
+    assert_eq!(report.warnings.len(), 1);
+    assert_eq!(report.warnings[0].code, "deprecated-key");
+    assert!(report.warnings[0].message.contains("read_timeout"));
+}
+
+#[cfg(feature = "secrets")]
+#[rocket::async_test]
+async fn preflight_known_secret_key() {
+    let key = "hPRYyVRiMyxpw5sBB1XeCMN1kFsDCqKvBi2QJxBVHQk=";
+    let figment = figment().merge(("secret_key", key)).merge(("log", "normal"));
+    let report = rocket::custom(figment).preflight().await;
+    let codes: Vec<_> = report.warnings.iter().map(|w| w.code).collect();
+    assert_eq!(codes, ["deprecated-key", "known-secret-key"]);
+}
+
+#[rocket::async_test]
+async fn preflight_collisions() {
+    let rocket = rocket::custom(figment()).mount("/", routes![index, other_index]);
+    let report = rocket.preflight().await;
+    assert!(report.warnings.is_empty());
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(report.errors[0].code, "collisions");
+
+    let message = &report.errors[0].message;
+    assert!(message.contains("route `index` (GET /)"), "{}", message);
+    assert!(message.contains("route `other_index` (GET /)"), "{}", message);
+}
+
+#[rocket::async_test]
+async fn preflight_config_error_with_deprecations() {
+    let figment = figment().merge(("port", "not a port")).merge(("read_timeout", 5));
+    let report = rocket::custom(figment).preflight().await;
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(report.errors[0].code, "config");
+    assert!(report.errors[0].message.contains("port"), "{}", report.errors[0].message);
+
+    assert_eq!(report.warnings.len(), 1);
+    assert_eq!(report.warnings[0].code, "deprecated-key");
+}
+
+#[rocket::async_test]
+async fn preflight_fairing_diagnostics() {
+    let rocket = rocket::custom(figment().merge(("read_timeout", 5)))
+        .attach(AdHoc::on_ignite("Warn", |rocket| async move {
+            rocket.preflight_warning("cache-disabled", "no cache size configured")
+        }))
+        .attach(AdHoc::try_on_ignite("Fail", |rocket| async move {
+            Err(rocket.preflight_error("database", "`database.url` is missing"))
+        }));
+
+    let report = rocket.preflight().await;
+    let codes: Vec<_> = report.errors.iter().map(|e| e.code).collect();
+    assert_eq!(codes, ["database", "failed-fairings"]);
+    assert!(report.errors[0].message.contains("database.url"));
+    assert!(report.errors[1].message.contains("`Fail`"));
+
+    let codes: Vec<_> = report.warnings.iter().map(|w| w.code).collect();
+    assert_eq!(codes, ["cache-disabled", "deprecated-key"]);
+}
@@ -0,0 +1,70 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Request, Build, Rocket};
+use rocket::fairing::AdHoc;
+use rocket::http::{Header, Status};
+use rocket::local::blocking::Client;
+
+#[get("/account/<_id>/delete", rank = 1)]
+fn delete_by_id(_id: u32) -> &'static str {
+    "deleted by id"
+}
+
+#[get("/account/<_name>/delete", rank = 2)]
+fn delete(_name: &str) -> &'static str {
+    "deleted"
+}
+
+#[get("/fail")]
+fn fail() -> Status {
+    Status::BadRequest
+}
+
+#[catch(default)]
+fn catcher(req: &Request<'_>) -> String {
+    req.route().map(|r| r.uri.to_string()).unwrap_or_else(|| "none".into())
+}
+
+fn rocket() -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![delete_by_id, delete, fail])
+        .register("/", catchers![catcher])
+        .attach(AdHoc::on_request("Route on Request", |req, _| Box::pin(async move {
+            assert!(req.route().is_none());
+        })))
+        .attach(AdHoc::on_response("Route on Response", |req, res| Box::pin(async move {
+            let name = req.route().and_then(|r| r.name.as_deref()).unwrap_or("none");
+            res.set_header(Header::new("X-Route", name.to_string()));
+        })))
+}
+
+#[test]
+fn route_is_final_route_after_forward() {
+    let client = Client::debug(rocket()).unwrap();
+    let response = client.get("/account/10/delete").dispatch();
+    assert_eq!(response.headers().get_one("X-Route"), Some("delete_by_id"));
+    assert_eq!(response.into_string().unwrap(), "deleted by id");
+
+    // `delete_by_id` forwards as `bob` isn't a `u32`.
+    let response = client.get("/account/bob/delete").dispatch();
+    assert_eq!(response.headers().get_one("X-Route"), Some("delete"));
+    assert_eq!(response.into_string().unwrap(), "deleted");
+}
+
+#[test]
+fn route_is_set_in_catchers() {
+    let client = Client::debug(rocket()).unwrap();
+    let response = client.get("/fail").dispatch();
+    assert_eq!(response.status(), Status::BadRequest);
+    assert_eq!(response.headers().get_one("X-Route"), Some("fail"));
+    assert_eq!(response.into_string().unwrap(), "/fail");
+}
+
+#[test]
+fn route_is_none_without_match() {
+    let client = Client::debug(rocket()).unwrap();
+    let response = client.get("/nowhere").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.headers().get_one("X-Route"), Some("none"));
+    assert_eq!(response.into_string().unwrap(), "none");
+}
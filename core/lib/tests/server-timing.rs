@@ -0,0 +1,120 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+
+use rocket::{Rocket, Build, Config};
+use rocket::fairing::AdHoc;
+use rocket::http::Status;
+use rocket::request::Timings;
+use rocket::local::blocking::Client;
+
+#[get("/")]
+fn index() -> &'static str {
+    "index"
+}
+
+#[get("/db")]
+fn db(timings: &Timings) -> &'static str {
+    timings.add("db", Duration::from_millis(3));
+    timings.add("bad name", Duration::from_millis(1));
+    "db"
+}
+
+fn rocket(server_timing: bool) -> Rocket<Build> {
+    let config = Config { server_timing, ..Config::debug_default() };
+    rocket::custom(config)
+        .mount("/", routes![index, db])
+        .attach(AdHoc::on_response("Cache Timing", |req, _| Box::pin(async move {
+            Timings::of(req).add("cache", Duration::from_micros(250));
+        })))
+}
+
+fn parse(header: &str) -> Vec<(&str, f64)> {
+    header.split(", ")
+        .map(|entry| {
+            let (name, dur) = entry.split_once(";dur=").expect("name;dur=value");
+            let (_, frac) = dur.split_once('.').expect("decimal milliseconds");
+            assert_eq!(frac.len(), 3);
+            (name, dur.parse().expect("valid float"))
+        })
+        .collect()
+}
+
+#[test]
+fn server_timing_header_present() {
+    let client = Client::debug(rocket(true)).unwrap();
+    let response = client.get("/").dispatch();
+    let header = response.headers().get_one("Server-Timing").expect("header");
+    let entries = parse(header);
+
+    let names: Vec<_> = entries.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, ["cache", "handler", "total"]);
+
+    let (handler, total) = (entries[1].1, entries[2].1);
+    assert!(handler >= 0.0 && handler <= total);
+}
+
+#[test]
+fn server_timing_merges_custom_entries() {
+    let client = Client::debug(rocket(true)).unwrap();
+    let response = client.get("/db").dispatch();
+    let header = response.headers().get_one("Server-Timing").expect("header");
+    let entries = parse(header);
+
+    let names: Vec<_> = entries.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, ["db", "cache", "handler", "total"]);
+    assert_eq!(entries[0].1, 3.0);
+    assert_eq!(entries[1].1, 0.25);
+}
+
+#[test]
+fn server_timing_header_on_error_responses() {
+    let client = Client::debug(rocket(true)).unwrap();
+    let response = client.get("/missing").dispatch();
+    assert!(response.headers().contains("Server-Timing"));
+}
+
+#[test]
+fn server_timing_total_includes_request_fairings() {
+    let rocket = rocket(true)
+        .attach(AdHoc::on_request("Slow", |_, _| Box::pin(async move {
+            rocket::tokio::time::sleep(Duration::from_millis(20)).await;
+        })));
+
+    let client = Client::debug(rocket).unwrap();
+    let response = client.get("/").dispatch();
+    let header = response.headers().get_one("Server-Timing").expect("header");
+    let entries = parse(header);
+
+    let (handler, total) = (entries[1].1, entries[2].1);
+    assert!(total >= 20.0, "{}", header);
+    assert!(total - handler >= 20.0, "{}", header);
+}
+
+#[test]
+fn server_timing_header_on_malformed_requests() {
+    let client = Client::debug(rocket(true)).unwrap();
+    let response = client.get("this is a bad URI").dispatch();
+    assert_eq!(response.status(), Status::BadRequest);
+
+    let header = response.headers().get_one("Server-Timing").expect("header");
+    let names: Vec<_> = parse(header).into_iter().map(|(name, _)| name).collect();
+    assert_eq!(names, ["total"]);
+}
+
+#[test]
+fn server_timing_absent_on_upgrade() {
+    let client = Client::debug(rocket(true)).unwrap();
+    let response = client.get("/")
+        .header(rocket::http::Header::new("Upgrade", "websocket"))
+        .dispatch();
+
+    assert!(!response.headers().contains("Server-Timing"));
+}
+
+#[test]
+fn server_timing_absent_when_disabled() {
+    let client = Client::debug(rocket(false)).unwrap();
+    let response = client.get("/db").dispatch();
+    assert!(!response.headers().contains("Server-Timing"));
+}
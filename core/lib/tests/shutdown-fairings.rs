@@ -3,6 +3,7 @@
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 use rocket::fairing::AdHoc;
+use rocket::config::Duration;
 
 // Want to test:
 //
@@ -96,8 +97,8 @@ async fn async_slow_shutdown_doesnt_elongate_grace() {
     use rocket::local::asynchronous::Client;
 
     let mut config = rocket::Config::debug_default();
-    config.shutdown.grace = 1;
-    config.shutdown.mercy = 1;
+    config.shutdown.grace = Duration::from_secs(1);
+    config.shutdown.mercy = Duration::from_secs(1);
 
     let rocket = rocket::build()
         .manage(Flags::default())
@@ -138,8 +139,8 @@ fn background_tasks_dont_prevent_terminate() {
     }
 
     let mut config = rocket::Config::debug_default();
-    config.shutdown.grace = 1;
-    config.shutdown.mercy = 1;
+    config.shutdown.grace = Duration::from_secs(1);
+    config.shutdown.mercy = Duration::from_secs(1);
 
     let rocket = rocket::build().reconfigure(config).mount("/", routes![index]);
 
@@ -1,4 +1,4 @@
-use rocket::config::{Config, LogLevel};
+use rocket::config::{Config, LogLevel, Duration};
 
 async fn test_config(profile: &str) {
     let provider = Config::figment().select(profile);
@@ -7,12 +7,12 @@ async fn test_config(profile: &str) {
     match profile {
         "debug" => {
             assert_eq!(config.workers, 1);
-            assert_eq!(config.keep_alive, 0);
+            assert_eq!(config.keep_alive, Duration::ZERO);
             assert_eq!(config.log_level, LogLevel::Normal);
         }
         "release" => {
             assert_eq!(config.workers, 12);
-            assert_eq!(config.keep_alive, 5);
+            assert_eq!(config.keep_alive, Duration::from_secs(5));
             assert_eq!(config.log_level, LogLevel::Critical);
             assert!(!config.secret_key.is_zero());
         }